@@ -0,0 +1,187 @@
+// Copyright (c) 2023 Yegor Bugayenko
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+#![feature(test)]
+
+extern crate test;
+use microstack::algorithms::{check_brackets, eval_postfix, to_postfix, Operator, Token};
+use test::Bencher;
+
+const DEPTH: usize = 256;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Op {
+    Add,
+    Mul,
+}
+
+impl Operator for Op {
+    fn precedence(&self) -> u8 {
+        match self {
+            Self::Add => 1,
+            Self::Mul => 2,
+        }
+    }
+}
+
+fn apply(op: Op, a: u64, b: u64) -> Option<u64> {
+    match op {
+        Op::Add => a.checked_add(b),
+        Op::Mul => a.checked_mul(b),
+    }
+}
+
+fn infix() -> Vec<Token<u64, Op>> {
+    let mut t = vec![];
+    for i in 0..DEPTH / 8 {
+        t.extend([
+            Token::Open,
+            Token::Operand(i as u64 % 7),
+            Token::Operator(Op::Add),
+            Token::Operand(3),
+            Token::Close,
+            Token::Operator(Op::Mul),
+            Token::Operand(2),
+            Token::Operator(Op::Add),
+        ]);
+    }
+    t.push(Token::Operand(1));
+    t
+}
+
+fn to_postfix_with_vec(infix: &[Token<u64, Op>]) -> Vec<Token<u64, Op>> {
+    let mut out = Vec::with_capacity(DEPTH);
+    let mut ops: Vec<Token<u64, Op>> = Vec::with_capacity(DEPTH);
+    for t in infix {
+        match *t {
+            Token::Operand(_) => out.push(*t),
+            Token::Open => ops.push(*t),
+            Token::Close => {
+                while let Some(op) = ops.pop() {
+                    if op == Token::Open {
+                        break;
+                    }
+                    out.push(op);
+                }
+            }
+            Token::Operator(o) => {
+                while let Some(Token::Operator(p)) = ops.last() {
+                    if p.precedence() < o.precedence() {
+                        break;
+                    }
+                    out.push(ops.pop().unwrap());
+                }
+                ops.push(*t);
+            }
+        }
+    }
+    while let Some(op) = ops.pop() {
+        out.push(op);
+    }
+    out
+}
+
+fn eval_postfix_with_vec(postfix: &[Token<u64, Op>]) -> Option<u64> {
+    let mut s: Vec<u64> = Vec::with_capacity(DEPTH);
+    for t in postfix {
+        match *t {
+            Token::Operand(v) => s.push(v),
+            Token::Operator(o) => {
+                let b = s.pop()?;
+                let a = s.pop()?;
+                s.push(apply(o, a, b)?);
+            }
+            Token::Open | Token::Close => return None,
+        }
+    }
+    s.pop()
+}
+
+fn text() -> String {
+    "([{".repeat(DEPTH / 3) + &"}])".repeat(DEPTH / 3)
+}
+
+fn check_brackets_with_vec(text: &str) -> bool {
+    let mut s: Vec<u8> = Vec::with_capacity(DEPTH);
+    for c in text.bytes() {
+        let open = match c {
+            b'(' | b'[' | b'{' => {
+                s.push(c);
+                continue;
+            }
+            b')' => b'(',
+            b']' => b'[',
+            b'}' => b'{',
+            _ => continue,
+        };
+        if s.pop() != Some(open) {
+            return false;
+        }
+    }
+    s.is_empty()
+}
+
+#[bench]
+fn stack_checks_brackets(b: &mut Bencher) {
+    let t = text();
+    b.iter(|| {
+        assert!(check_brackets::<DEPTH>(&t).is_ok());
+    });
+}
+
+#[bench]
+fn vec_checks_brackets(b: &mut Bencher) {
+    let t = text();
+    b.iter(|| {
+        assert!(check_brackets_with_vec(&t));
+    });
+}
+
+#[bench]
+fn stack_converts_to_postfix(b: &mut Bencher) {
+    let t = infix();
+    b.iter(|| {
+        assert!(to_postfix::<_, _, DEPTH>(t.iter().copied()).is_ok());
+    });
+}
+
+#[bench]
+fn vec_converts_to_postfix(b: &mut Bencher) {
+    let t = infix();
+    b.iter(|| {
+        assert!(!to_postfix_with_vec(&t).is_empty());
+    });
+}
+
+#[bench]
+fn stack_evaluates_postfix(b: &mut Bencher) {
+    let p = to_postfix_with_vec(&infix());
+    b.iter(|| {
+        assert!(eval_postfix::<_, _, DEPTH>(p.iter().copied(), apply).is_ok());
+    });
+}
+
+#[bench]
+fn vec_evaluates_postfix(b: &mut Bencher) {
+    let p = to_postfix_with_vec(&infix());
+    b.iter(|| {
+        assert!(eval_postfix_with_vec(&p).is_some());
+    });
+}
@@ -30,10 +30,10 @@ fn push_different(b: &mut Bencher) {
     b.iter(|| {
         let cap = s.capacity();
         for i in 0..cap {
-            s.push_unchecked(i);
+            unsafe { s.push_unchecked(i) };
         }
         for _ in 0..cap {
-            s.pop_unchecked();
+            unsafe { s.pop_unchecked() };
         }
     });
 }
@@ -44,8 +44,8 @@ fn push_and_pop(b: &mut Bencher) {
     b.iter(|| {
         let cap = s.capacity();
         for i in 0..cap {
-            s.push_unchecked(i);
-            s.pop_unchecked();
+            unsafe { s.push_unchecked(i) };
+            unsafe { s.pop_unchecked() };
         }
     });
 }
@@ -0,0 +1,58 @@
+// Copyright (c) 2023 Yegor Bugayenko
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+#![feature(test)]
+
+extern crate test;
+use microstack::Heap;
+use std::collections::BinaryHeap;
+use test::Bencher;
+
+const CAPACITY: usize = 1024;
+
+macro_rules! eval {
+    ($s:expr) => {{
+        let mut x: usize = 1;
+        for _ in 0..CAPACITY {
+            x = x.wrapping_mul(6_364_136_223_846_793_005).wrapping_add(1);
+            $s.push(x >> 40);
+        }
+        for _ in 0..CAPACITY {
+            $s.pop();
+        }
+        $s.clear();
+    }};
+}
+
+#[bench]
+fn heap_push_and_pop(b: &mut Bencher) {
+    b.iter(|| {
+        let mut s: Heap<usize, CAPACITY> = Heap::new();
+        eval!(s);
+    });
+}
+
+#[bench]
+fn binary_heap_push_and_pop(b: &mut Bencher) {
+    b.iter(|| {
+        let mut s: BinaryHeap<usize> = BinaryHeap::with_capacity(CAPACITY);
+        eval!(s);
+    });
+}
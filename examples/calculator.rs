@@ -0,0 +1,77 @@
+// Copyright (c) 2023 Yegor Bugayenko
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! Calculates an arithmetic expression of single-digit numbers,
+//! given as the first command line argument, for example:
+//!
+//! ```text
+//! $ cargo run --example calculator '(1+2)*3-4'
+//! ```
+
+use microstack::algorithms::{check_brackets, eval_postfix, to_postfix, Operator, Token};
+
+#[derive(Clone, Copy)]
+enum Op {
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+impl Operator for Op {
+    fn precedence(&self) -> u8 {
+        match self {
+            Self::Add | Self::Sub => 1,
+            Self::Mul | Self::Div => 2,
+        }
+    }
+}
+
+fn main() {
+    let text = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| "(1+2)*3-4".to_string());
+    if let Err(e) = check_brackets::<64>(&text) {
+        eprintln!("{e}");
+        return;
+    }
+    let tokens = text.bytes().filter_map(|c| match c {
+        b'+' => Some(Token::Operator(Op::Add)),
+        b'-' => Some(Token::Operator(Op::Sub)),
+        b'*' => Some(Token::Operator(Op::Mul)),
+        b'/' => Some(Token::Operator(Op::Div)),
+        b'(' => Some(Token::Open),
+        b')' => Some(Token::Close),
+        b'0'..=b'9' => Some(Token::Operand(i64::from(c - b'0'))),
+        _ => None,
+    });
+    let result = to_postfix::<_, _, 64>(tokens).and_then(|postfix| {
+        eval_postfix::<_, _, 64>(postfix, |op, a: i64, b| match op {
+            Op::Add => a.checked_add(b),
+            Op::Sub => a.checked_sub(b),
+            Op::Mul => a.checked_mul(b),
+            Op::Div => a.checked_div(b),
+        })
+    });
+    match result {
+        Ok(v) => println!("{text} = {v}"),
+        Err(e) => eprintln!("{e}"),
+    }
+}
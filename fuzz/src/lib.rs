@@ -0,0 +1,142 @@
+// Copyright (c) 2023 Yegor Bugayenko
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! Replays of fuzzer inputs against [`Stack`], shared by the fuzz
+//! targets in `fuzz_targets/` and the corpus regression test in `tests/`.
+
+use arbitrary::{Arbitrary, Unstructured};
+use microstack::Stack;
+
+/// The capacity of the stacks under test, small enough to overflow often.
+const N: usize = 16;
+
+/// A single operation on a stack.
+#[derive(Arbitrary, Clone, Copy, Debug)]
+pub enum Op {
+    Push(u8),
+    Pop,
+    TryPush(u8),
+    TryPop,
+    Insert(u8, u8),
+    Remove(u8),
+    SwapRemove(u8),
+    Retain(u8),
+    Dedup,
+    PopWhile(u8),
+    Clear,
+    Clone,
+    Iter,
+}
+
+/// Apply a sequence of operations, decoded from the bytes, to an arbitrary
+/// stack and to a `Vec`, panicking as soon as they disagree.
+pub fn ops(data: &[u8]) {
+    let u = Unstructured::new(data);
+    let Ok((mut s, ops)) = <(Stack<u8, N>, Vec<Op>)>::arbitrary_take_rest(u) else {
+        return;
+    };
+    let mut model: Vec<u8> = s.iter().copied().collect();
+    for op in ops {
+        match op {
+            Op::Push(v) => {
+                if model.len() < N {
+                    s.push(v);
+                    model.push(v);
+                }
+            }
+            Op::Pop => {
+                if let Some(v) = model.pop() {
+                    assert_eq!(v, s.pop());
+                }
+            }
+            Op::TryPush(v) => {
+                let pushed = s.try_push(v).is_ok();
+                assert_eq!(model.len() < N, pushed);
+                if pushed {
+                    model.push(v);
+                }
+            }
+            Op::TryPop => assert_eq!(model.pop(), s.try_pop().ok()),
+            Op::Insert(i, v) => {
+                let i = usize::from(i);
+                let inserted = s.try_insert(i, v).is_ok();
+                assert_eq!(i <= model.len() && model.len() < N, inserted);
+                if inserted {
+                    model.insert(i, v);
+                }
+            }
+            Op::Remove(i) => {
+                let i = usize::from(i);
+                let removed = s.try_remove(i).ok();
+                assert_eq!((i < model.len()).then(|| model.remove(i)), removed);
+            }
+            Op::SwapRemove(i) => {
+                let i = usize::from(i);
+                let removed = s.try_swap_remove(i).ok();
+                assert_eq!((i < model.len()).then(|| model.swap_remove(i)), removed);
+            }
+            Op::Retain(m) => {
+                s.retain(|v| v % 2 == m % 2);
+                model.retain(|v| v % 2 == m % 2);
+            }
+            Op::Dedup => {
+                s.dedup();
+                model.dedup();
+            }
+            Op::PopWhile(m) => {
+                let popped: Vec<u8> = s.pop_while(|v| *v >= m).collect();
+                let mut expected = vec![];
+                while let Some(v) = model.pop_if(|v| *v >= m) {
+                    expected.push(v);
+                }
+                assert_eq!(expected, popped);
+            }
+            Op::Clear => {
+                s.clear();
+                model.clear();
+            }
+            Op::Clone => s = s.clone(),
+            Op::Iter => assert!(model.iter().eq(s.iter())),
+        }
+        assert_eq!(model.len(), s.len());
+    }
+    assert_eq!(model, s.into_iter().collect::<Vec<_>>());
+}
+
+/// Deserialize stacks of a few shapes from the raw bytes, making sure
+/// nothing panics and that whatever was accepted survives a round trip.
+pub fn serde(data: &[u8]) {
+    round_trip::<u8, 4>(data);
+    round_trip::<u8, N>(data);
+    round_trip::<u64, 2>(data);
+}
+
+fn round_trip<V, const M: usize>(data: &[u8])
+where
+    V: Copy + PartialEq + std::fmt::Debug + serde::Serialize + serde::de::DeserializeOwned,
+{
+    let Ok(s) = bincode::deserialize::<Stack<V, M>>(data) else {
+        return;
+    };
+    assert!(s.len() <= M);
+    let bytes = bincode::serialize(&s).unwrap();
+    let after: Stack<V, M> = bincode::deserialize(&bytes).unwrap();
+    assert!(s.iter().eq(after.iter()));
+}
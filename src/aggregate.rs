@@ -0,0 +1,261 @@
+// Copyright (c) 2023 Yegor Bugayenko
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{AggregateStack, Stack};
+
+impl<V: Copy, const N: usize, F: Fn(V, V) -> V> AggregateStack<V, N, F> {
+    /// Make it, with an associative operator to fold elements with.
+    #[inline]
+    #[must_use]
+    pub const fn new(op: F) -> Self {
+        Self {
+            stack: Stack::new(),
+            op,
+        }
+    }
+
+    /// Push new element into it.
+    ///
+    /// # Safety
+    ///
+    /// It may lead to undefined behavior, if you go over the boundary.
+    #[inline]
+    pub unsafe fn push_unchecked(&mut self, v: V) {
+        let acc = if self.stack.next == 0 {
+            v
+        } else {
+            (self.op)(self.fold_unchecked(), v)
+        };
+        self.stack.push_unchecked((v, acc));
+    }
+
+    /// Push new element into it.
+    ///
+    /// # Panics
+    ///
+    /// If there is no more space in the stack, it will panic.
+    #[inline]
+    pub fn push(&mut self, v: V) {
+        assert!(self.stack.next < N, "No more space left in the stack");
+        unsafe {
+            self.push_unchecked(v);
+        }
+    }
+
+    /// Makes an attempt to push a new element into the stack.
+    ///
+    /// # Errors
+    ///
+    /// If there is not enough space in the stack, `Err` is returned.
+    #[inline]
+    pub fn try_push(&mut self, v: V) -> Result<(), String> {
+        if self.stack.next < N {
+            self.push(v);
+            Ok(())
+        } else {
+            Err(format!("There are no space left in the stack of {N}"))
+        }
+    }
+
+    /// Pop a element from it.
+    ///
+    /// # Safety
+    ///
+    /// If there are no items in the array, the result is undefined.
+    #[inline]
+    pub const unsafe fn pop_unchecked(&mut self) -> V {
+        self.stack.pop_unchecked().0
+    }
+
+    /// Pop a element from it.
+    ///
+    /// # Panics
+    ///
+    /// If there are no items in the array, it will panic.
+    #[inline]
+    pub fn pop(&mut self) -> V {
+        self.stack.pop().0
+    }
+
+    /// Pop a element from it.
+    ///
+    /// # Errors
+    ///
+    /// If there is no more elements left, it will return `Err`.
+    #[inline]
+    pub fn try_pop(&mut self) -> Result<V, String> {
+        self.stack.try_pop().map(|(v, _)| v)
+    }
+
+    /// Get the top element, without popping it.
+    ///
+    /// # Safety
+    ///
+    /// If there are no items in the array, the result is undefined.
+    #[inline]
+    #[must_use]
+    pub const unsafe fn peek_unchecked(&self) -> V {
+        self.stack.items.as_ptr().add(self.stack.next - 1).read().0
+    }
+
+    /// Get the top element, without popping it.
+    ///
+    /// # Panics
+    ///
+    /// If there are no items in the array, it will panic.
+    #[inline]
+    #[must_use]
+    pub fn peek(&self) -> V {
+        assert!(self.stack.next > 0, "No more items left in the stack");
+        unsafe { self.peek_unchecked() }
+    }
+
+    /// Get the top element, without popping it.
+    ///
+    /// # Errors
+    ///
+    /// If there is no elements, it will return `Err`.
+    #[inline]
+    pub fn try_peek(&self) -> Result<V, String> {
+        if self.stack.next == 0 {
+            Err(format!("There are no items left in the stack of {N}"))
+        } else {
+            Ok(self.peek())
+        }
+    }
+
+    /// Get the result of folding all elements, from bottom to top.
+    ///
+    /// # Safety
+    ///
+    /// If there are no items in the array, the result is undefined.
+    #[inline]
+    #[must_use]
+    pub const unsafe fn fold_unchecked(&self) -> V {
+        self.stack.items.as_ptr().add(self.stack.next - 1).read().1
+    }
+
+    /// Get the result of folding all elements, from bottom to top.
+    ///
+    /// # Panics
+    ///
+    /// If there are no items in the array, it will panic.
+    #[inline]
+    #[must_use]
+    pub fn fold(&self) -> V {
+        assert!(self.stack.next > 0, "No more items left in the stack");
+        unsafe { self.fold_unchecked() }
+    }
+
+    /// Get the result of folding all elements, from bottom to top.
+    ///
+    /// # Errors
+    ///
+    /// If there is no elements, it will return `Err`.
+    #[inline]
+    pub fn try_fold(&self) -> Result<V, String> {
+        if self.stack.next == 0 {
+            Err(format!("There are no items left in the stack of {N}"))
+        } else {
+            Ok(self.fold())
+        }
+    }
+
+    /// Clear.
+    #[inline]
+    pub const fn clear(&mut self) {
+        self.stack.clear();
+    }
+
+    /// Is it empty.
+    #[inline]
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.stack.is_empty()
+    }
+
+    /// Length of it.
+    #[inline]
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.stack.len()
+    }
+}
+
+#[test]
+fn folds_sum() {
+    let mut s = AggregateStack::<u64, 8, _>::new(|a, b| a + b);
+    s.push(1);
+    s.push(2);
+    s.push(3);
+    assert_eq!(6, s.fold());
+    assert_eq!(3, s.pop());
+    assert_eq!(3, s.fold());
+}
+
+#[test]
+fn folds_gcd() {
+    const fn gcd(a: u64, b: u64) -> u64 {
+        if b == 0 {
+            a
+        } else {
+            gcd(b, a % b)
+        }
+    }
+    let mut s = AggregateStack::<u64, 8, _>::new(gcd);
+    s.push(36);
+    s.push(24);
+    assert_eq!(12, s.fold());
+    s.push(9);
+    assert_eq!(3, s.fold());
+    s.pop();
+    assert_eq!(12, s.fold());
+}
+
+#[test]
+fn folds_xor() {
+    let mut s = AggregateStack::<u8, 8, _>::new(|a, b| a ^ b);
+    s.push(0b1100);
+    s.push(0b1010);
+    assert_eq!(0b0110, s.fold());
+    assert_eq!(0b1010, s.peek());
+}
+
+#[test]
+fn fails_on_empty_stack() {
+    let mut s = AggregateStack::<u8, 1, _>::new(|a, b| a ^ b);
+    assert!(s.try_fold().err().unwrap().contains("left"));
+    assert!(s.try_peek().is_err());
+    assert!(s.try_pop().is_err());
+    assert!(s.try_push(1).is_ok());
+    assert!(s.try_push(1).err().unwrap().contains("space"));
+    assert_eq!(Ok(1), s.try_fold());
+}
+
+#[test]
+fn folds_after_clear() {
+    let mut s = AggregateStack::<i32, 4, _>::new(i32::max);
+    s.push(10);
+    s.clear();
+    assert!(s.is_empty());
+    s.push(-3);
+    assert_eq!(-3, s.fold());
+    assert_eq!(1, s.len());
+}
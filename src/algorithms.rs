@@ -0,0 +1,346 @@
+// Copyright (c) 2023 Yegor Bugayenko
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! Classic stack-based algorithms, which never allocate on heap.
+//!
+//! For example, here is how an infix expression can be calculated:
+//!
+//! ```
+//! use microstack::algorithms::{eval_postfix, to_postfix, Operator, Token};
+//! #[derive(Clone, Copy)]
+//! enum Op { Add, Mul }
+//! impl Operator for Op {
+//!     fn precedence(&self) -> u8 {
+//!         match self { Op::Add => 1, Op::Mul => 2 }
+//!     }
+//! }
+//! let infix = [
+//!     Token::Operand(2), Token::Operator(Op::Add),
+//!     Token::Operand(3), Token::Operator(Op::Mul), Token::Operand(4),
+//! ];
+//! let postfix = to_postfix::<_, _, 8>(infix).unwrap();
+//! let x = eval_postfix::<_, _, 8>(postfix, |op, a: i64, b| match op {
+//!     Op::Add => a.checked_add(b),
+//!     Op::Mul => a.checked_mul(b),
+//! });
+//! assert_eq!(Ok(14), x);
+//! ```
+
+use crate::Stack;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+
+/// A failure of an algorithm.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// There is not enough space in the stack.
+    Overflow,
+    /// Brackets don't match.
+    Unbalanced,
+    /// There are too many or too few operands for the operators.
+    Malformed,
+    /// The operator can't be applied to its operands.
+    Apply,
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Overflow => "There is not enough space in the stack",
+            Self::Unbalanced => "The brackets are not balanced",
+            Self::Malformed => "The expression is malformed",
+            Self::Apply => "The operator can't be applied to its operands",
+        })
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// A binary operator of an expression.
+pub trait Operator: Copy {
+    /// The precedence of it, higher binds tighter.
+    fn precedence(&self) -> u8;
+
+    /// Is it right-associative, like exponentiation.
+    fn right_associative(&self) -> bool {
+        false
+    }
+}
+
+/// A token of an expression.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Token<T, O> {
+    /// A value.
+    Operand(T),
+    /// A binary operator.
+    Operator(O),
+    /// An opening parenthesis.
+    Open,
+    /// A closing parenthesis.
+    Close,
+}
+
+/// Push the token, failing with [`Error::Overflow`] if it doesn't fit.
+#[inline]
+fn put<V: Copy, const N: usize>(s: &mut Stack<V, N>, v: V) -> Result<(), Error> {
+    if s.len() < N {
+        unsafe { s.push_unchecked(v) };
+        Ok(())
+    } else {
+        Err(Error::Overflow)
+    }
+}
+
+/// Convert an infix expression into a postfix one, using
+/// the shunting-yard algorithm by Dijkstra.
+///
+/// The capacity `N` limits both the number of operators waiting
+/// in the stack and the length of the result.
+///
+/// # Errors
+///
+/// If parentheses are not balanced, [`Error::Unbalanced`] is returned.
+/// If there is not enough space, [`Error::Overflow`] is returned.
+pub fn to_postfix<T: Copy, O: Operator, const N: usize>(
+    infix: impl IntoIterator<Item = Token<T, O>>,
+) -> Result<Stack<Token<T, O>, N>, Error> {
+    let mut out: Stack<Token<T, O>, N> = Stack::new();
+    let mut ops: Stack<Token<T, O>, N> = Stack::new();
+    for t in infix {
+        match t {
+            Token::Operand(_) => put(&mut out, t)?,
+            Token::Open => put(&mut ops, t)?,
+            Token::Close => {
+                for op in ops.pop_until(|op| matches!(op, Token::Open)) {
+                    put(&mut out, op)?;
+                }
+                ops.pop_if(|op| matches!(op, Token::Open))
+                    .ok_or(Error::Unbalanced)?;
+            }
+            Token::Operator(o) => {
+                let binds = |top: &Token<T, O>| match top {
+                    Token::Operator(p) => {
+                        p.precedence() > o.precedence()
+                            || (p.precedence() == o.precedence() && !o.right_associative())
+                    }
+                    _ => false,
+                };
+                for op in ops.pop_while(binds) {
+                    put(&mut out, op)?;
+                }
+                put(&mut ops, t)?;
+            }
+        }
+    }
+    for op in ops.pop_while(|_| true) {
+        if matches!(op, Token::Open) {
+            return Err(Error::Unbalanced);
+        }
+        put(&mut out, op)?;
+    }
+    Ok(out)
+}
+
+/// Calculate a postfix expression, also known as the reverse Polish
+/// notation, applying operators to operands with the function.
+///
+/// The function returns `None` if the operator can't be applied,
+/// for example on division by zero or arithmetic overflow.
+///
+/// The capacity `N` limits the number of operands waiting in the stack.
+///
+/// # Errors
+///
+/// If there are too many or too few operands, or parentheses are met,
+/// [`Error::Malformed`] is returned. If there is not enough space,
+/// [`Error::Overflow`] is returned. If the function returns `None`,
+/// [`Error::Apply`] is returned.
+pub fn eval_postfix<T: Copy, O: Copy, const N: usize>(
+    postfix: impl IntoIterator<Item = Token<T, O>>,
+    mut apply: impl FnMut(O, T, T) -> Option<T>,
+) -> Result<T, Error> {
+    let mut s: Stack<T, N> = Stack::new();
+    for t in postfix {
+        match t {
+            Token::Operand(v) => put(&mut s, v)?,
+            Token::Operator(o) => {
+                let b = s.try_pop().map_err(|_| Error::Malformed)?;
+                let a = s.try_pop().map_err(|_| Error::Malformed)?;
+                put(&mut s, apply(o, a, b).ok_or(Error::Apply)?)?;
+            }
+            Token::Open | Token::Close => return Err(Error::Malformed),
+        }
+    }
+    let v = s.try_pop().map_err(|_| Error::Malformed)?;
+    if s.is_empty() {
+        Ok(v)
+    } else {
+        Err(Error::Malformed)
+    }
+}
+
+/// Check that all brackets `()`, `[]`, and `{}` in the text are
+/// balanced, ignoring all other chars.
+///
+/// The capacity `N` limits the depth of nesting.
+///
+/// # Errors
+///
+/// If brackets are not balanced, [`Error::Unbalanced`] is returned.
+/// If they are nested deeper than `N`, [`Error::Overflow`] is returned.
+pub fn check_brackets<const N: usize>(text: &str) -> Result<(), Error> {
+    let mut s: Stack<u8, N> = Stack::new();
+    for c in text.bytes() {
+        let open = match c {
+            b'(' | b'[' | b'{' => {
+                put(&mut s, c)?;
+                continue;
+            }
+            b')' => b'(',
+            b']' => b'[',
+            b'}' => b'{',
+            _ => continue,
+        };
+        s.pop_if(|top| *top == open).ok_or(Error::Unbalanced)?;
+    }
+    if s.is_empty() {
+        Ok(())
+    } else {
+        Err(Error::Unbalanced)
+    }
+}
+
+#[cfg(test)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Op {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Pow,
+}
+
+#[cfg(test)]
+impl Operator for Op {
+    fn precedence(&self) -> u8 {
+        match self {
+            Self::Add | Self::Sub => 1,
+            Self::Mul | Self::Div => 2,
+            Self::Pow => 3,
+        }
+    }
+
+    fn right_associative(&self) -> bool {
+        *self == Self::Pow
+    }
+}
+
+#[cfg(test)]
+fn tokens(text: &str) -> Vec<Token<i64, Op>> {
+    text.bytes()
+        .map(|c| match c {
+            b'+' => Token::Operator(Op::Add),
+            b'-' => Token::Operator(Op::Sub),
+            b'*' => Token::Operator(Op::Mul),
+            b'/' => Token::Operator(Op::Div),
+            b'^' => Token::Operator(Op::Pow),
+            b'(' => Token::Open,
+            b')' => Token::Close,
+            _ => Token::Operand(i64::from(c - b'0')),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+fn calc(text: &str) -> Result<i64, Error> {
+    let postfix = to_postfix::<_, _, 32>(tokens(text))?;
+    eval_postfix::<_, _, 32>(postfix, |op, a, b| match op {
+        Op::Add => a.checked_add(b),
+        Op::Sub => a.checked_sub(b),
+        Op::Mul => a.checked_mul(b),
+        Op::Div => a.checked_div(b),
+        Op::Pow => a.checked_pow(u32::try_from(b).ok()?),
+    })
+}
+
+#[test]
+fn converts_to_postfix() {
+    let postfix = to_postfix::<_, _, 16>(tokens("3+4*(2-1)")).unwrap();
+    assert_eq!(tokens("3421-*+"), postfix.into_iter().collect::<Vec<_>>());
+}
+
+#[test]
+fn respects_associativity() {
+    assert_eq!(Ok(1), calc("8-4-3"));
+    assert_eq!(Ok(512), calc("2^3^2"));
+}
+
+#[test]
+fn calculates_expressions() {
+    assert_eq!(Ok(7), calc("1+2*3"));
+    assert_eq!(Ok(9), calc("(1+2)*3"));
+    assert_eq!(Ok(-4), calc("(1-5)*(2-1)"));
+}
+
+#[test]
+fn rejects_unbalanced_parentheses() {
+    assert_eq!(Err(Error::Unbalanced), calc("(1+2"));
+    assert_eq!(Err(Error::Unbalanced), calc("1+2)"));
+}
+
+#[test]
+fn rejects_malformed_postfix() {
+    assert_eq!(Err(Error::Malformed), calc("1+"));
+    assert_eq!(Err(Error::Malformed), calc("12"));
+    assert_eq!(Err(Error::Malformed), calc(""));
+}
+
+#[test]
+fn reports_overflow() {
+    assert_eq!(
+        Err(Error::Overflow),
+        to_postfix::<_, _, 2>(tokens("1+2")).map(|s| s.len())
+    );
+    assert_eq!(
+        Err(Error::Overflow),
+        eval_postfix::<i64, Op, 1>(tokens("12+"), |_, a, b| a.checked_add(b))
+    );
+}
+
+#[test]
+fn reports_failed_operators() {
+    assert_eq!(Ok(3), calc("7/2"));
+    assert_eq!(Err(Error::Apply), calc("1/(2-2)"));
+    assert_eq!(Err(Error::Apply), calc("9^9^9"));
+}
+
+#[test]
+fn checks_brackets() {
+    assert_eq!(Ok(()), check_brackets::<8>("fn f(x: [u8; 2]) { g(x) }"));
+    assert_eq!(Err(Error::Unbalanced), check_brackets::<8>("(]"));
+    assert_eq!(Err(Error::Unbalanced), check_brackets::<8>("(("));
+    assert_eq!(Err(Error::Unbalanced), check_brackets::<8>("}"));
+    assert_eq!(Err(Error::Overflow), check_brackets::<2>("((()))"));
+}
+
+#[test]
+fn prints_errors() {
+    assert!(Error::Overflow.to_string().contains("space"));
+}
@@ -18,7 +18,7 @@
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 // SOFTWARE.
 
-use crate::Stack;
+use crate::{Deque, Queue, Stack};
 use std::ptr;
 
 impl<V: Copy, const N: usize> Clone for Stack<V, N> {
@@ -31,6 +31,26 @@ impl<V: Copy, const N: usize> Clone for Stack<V, N> {
     }
 }
 
+impl<V: Copy, const N: usize> Clone for Deque<V, N> {
+    /// Clone it.
+    fn clone(&self) -> Self {
+        let mut d: Self = Self::new();
+        for v in self {
+            unsafe { d.push_back_unchecked(*v) };
+        }
+        d
+    }
+}
+
+impl<V: Copy, const N: usize> Clone for Queue<V, N> {
+    /// Clone it.
+    fn clone(&self) -> Self {
+        Self {
+            deque: self.deque.clone(),
+        }
+    }
+}
+
 #[test]
 fn stack_can_be_cloned() {
     let mut s: Stack<u8, 16> = Stack::new();
@@ -48,7 +68,26 @@ fn full_stack_can_be_cloned() {
 }
 
 #[test]
+#[allow(clippy::redundant_clone)]
 fn empty_stack_can_be_cloned() {
     let m: Stack<u8, 0> = Stack::new();
     assert!(m.clone().is_empty());
 }
+
+#[test]
+fn deque_can_be_cloned() {
+    let mut d: Deque<u8, 2> = Deque::new();
+    d.push_back(1);
+    d.push_back(2);
+    d.pop_front();
+    d.push_back(3);
+    assert_eq!(vec![2, 3], d.clone().into_iter().collect::<Vec<_>>());
+}
+
+#[test]
+fn queue_can_be_cloned() {
+    let mut q: Queue<u8, 4> = Queue::new();
+    q.push(42);
+    assert_eq!(42, q.clone().pop());
+    assert_eq!(1, q.len());
+}
@@ -44,6 +44,112 @@ impl<V: Copy, const N: usize> Stack<V, N> {
             }
         }
     }
+
+    /// Make it from an array of elements, the last one on top.
+    ///
+    /// Unlike [`Stack::new`], all slots are initialized (the unused ones with
+    /// copies of the first element, or with zero bytes if the array is
+    /// empty), which makes it usable in `const` contexts. This is what
+    /// the [`stack!`](crate::stack) macro expands to.
+    ///
+    /// In `const` contexts an empty array works only for elements, for
+    /// which zero bytes are a valid value, like numbers, `bool` and `char`:
+    ///
+    /// ```
+    /// use microstack::Stack;
+    /// const EMPTY : Stack<u8, 4> = Stack::from_array([]);
+    /// assert!(EMPTY.is_empty());
+    /// ```
+    ///
+    /// An array longer than the capacity fails to compile:
+    ///
+    /// ```compile_fail
+    /// use microstack::Stack;
+    /// let s : Stack<u8, 2> = Stack::from_array([1, 2, 3]);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn from_array<const M: usize>(items: [V; M]) -> Self {
+        const { assert!(M <= N, "Too many items for the stack") };
+        if M == 0 {
+            return Self {
+                next: 0,
+                items: unsafe { MaybeUninit::<[V; N]>::zeroed().assume_init() },
+            };
+        }
+        let mut all = [items[0]; N];
+        let mut i = 1;
+        while i < M {
+            all[i] = items[i];
+            i += 1;
+        }
+        Self {
+            next: M,
+            items: all,
+        }
+    }
+
+    /// Make it with `len` elements, produced by the function from
+    /// their positions, the first one at the bottom.
+    ///
+    /// # Panics
+    ///
+    /// If `len` is larger than the capacity, it will panic.
+    #[inline]
+    #[must_use]
+    pub fn from_fn<F: FnMut(usize) -> V>(len: usize, mut f: F) -> Self {
+        assert!(len <= N, "No more space left in the stack");
+        let mut p = Self::new();
+        for i in 0..len {
+            unsafe { p.push_unchecked(f(i)) };
+        }
+        p
+    }
+
+    /// Make it with `len` copies of the element.
+    ///
+    /// # Panics
+    ///
+    /// If `len` is larger than the capacity, it will panic.
+    #[inline]
+    #[must_use]
+    pub const fn repeat(v: V, len: usize) -> Self {
+        assert!(len <= N, "No more space left in the stack");
+        Self {
+            next: len,
+            items: [v; N],
+        }
+    }
+
+    /// Make it from the array, keeping only the first `len` elements.
+    ///
+    /// # Panics
+    ///
+    /// If `len` is larger than the capacity, it will panic.
+    #[inline]
+    #[must_use]
+    pub const fn from_array_partial(items: [V; N], len: usize) -> Self {
+        assert!(len <= N, "No more space left in the stack");
+        Self { next: len, items }
+    }
+
+    /// Make it from the first `N` elements of the iterator, returning
+    /// it together with the number of elements that didn't fit and
+    /// were dropped.
+    ///
+    /// The iterator is consumed entirely, in order to count them.
+    #[must_use]
+    pub fn from_iter_truncating<I: IntoIterator<Item = V>>(iter: I) -> (Self, usize) {
+        let mut p = Self::new();
+        let mut iter = iter.into_iter();
+        while p.next < N {
+            match iter.next() {
+                Some(v) => unsafe { p.push_unchecked(v) },
+                None => return (p, 0),
+            }
+        }
+        (p, iter.count())
+    }
 }
 
 #[test]
@@ -57,3 +163,125 @@ fn makes_new_stack() {
     let s: Stack<u8, 8> = Stack::new();
     assert_eq!(0, s.len());
 }
+
+#[test]
+fn makes_stack_from_array() {
+    let s: Stack<u8, 4> = Stack::from_array([1, 2, 3]);
+    assert_eq!(3, s.len());
+    assert_eq!(vec![1, 2, 3], s.into_iter().collect::<Vec<_>>());
+}
+
+#[test]
+fn makes_full_stack_from_array() {
+    let mut s: Stack<u8, 2> = Stack::from_array([1, 2]);
+    assert!(s.is_full());
+    assert_eq!(2, s.pop());
+}
+
+#[test]
+fn makes_empty_stack_from_array() {
+    let s: Stack<u8, 2> = Stack::from_array([]);
+    assert!(s.is_empty());
+}
+
+#[test]
+fn makes_stack_from_array_in_const() {
+    const S: Stack<u8, 8> = Stack::from_array([4, 2]);
+    assert_eq!(2, S.len());
+    assert_eq!(vec![4, 2], S.into_iter().collect::<Vec<_>>());
+}
+
+#[test]
+fn makes_stacks_with_macro_in_const() {
+    const EMPTY: Stack<u8, 4> = crate::stack![];
+    const ZEROS: Stack<u8, 4> = crate::stack![0; 3];
+    const LIST: Stack<char, 4> = crate::stack!['a', 'b'];
+    const NONE: Stack<bool, 4> = Stack::from_array([]);
+    assert!(EMPTY.is_empty());
+    assert!(NONE.is_empty());
+    assert_eq!(vec![0, 0, 0], ZEROS.into_iter().collect::<Vec<_>>());
+    assert_eq!(vec!['a', 'b'], LIST.into_iter().collect::<Vec<_>>());
+}
+
+#[cfg(test)]
+fn items<const N: usize>(s: Stack<usize, N>) -> Vec<usize> {
+    s.into_iter().collect()
+}
+
+#[test]
+fn makes_stack_from_fn() {
+    assert_eq!(
+        Vec::<usize>::new(),
+        items(Stack::<usize, 4>::from_fn(0, |i| i))
+    );
+    assert_eq!(
+        vec![0, 10, 20],
+        items(Stack::<usize, 4>::from_fn(3, |i| i * 10))
+    );
+    assert_eq!(
+        vec![0, 1, 2, 3],
+        items(Stack::<usize, 4>::from_fn(4, |i| i))
+    );
+}
+
+#[test]
+#[should_panic(expected = "No more space left in the stack")]
+fn fails_to_make_too_long_stack_from_fn() {
+    let _ = Stack::<usize, 4>::from_fn(5, |i| i);
+}
+
+#[test]
+fn makes_stack_by_repeating() {
+    assert!(Stack::<usize, 4>::repeat(7, 0).is_empty());
+    assert_eq!(vec![7, 7, 7], items(Stack::<usize, 4>::repeat(7, 3)));
+    assert_eq!(vec![7; 4], items(Stack::<usize, 4>::repeat(7, 4)));
+}
+
+#[test]
+fn makes_stack_by_repeating_in_const() {
+    const S: Stack<u8, 4> = Stack::repeat(1, 2);
+    assert_eq!(2, S.len());
+}
+
+#[test]
+#[should_panic(expected = "No more space left in the stack")]
+fn fails_to_make_too_long_stack_by_repeating() {
+    let _ = Stack::<usize, 4>::repeat(7, 5);
+}
+
+#[test]
+fn makes_stack_from_partial_array() {
+    let a = [1, 2, 3, 4];
+    assert!(Stack::from_array_partial(a, 0).is_empty());
+    assert_eq!(vec![1, 2, 3], items(Stack::from_array_partial(a, 3)));
+    assert_eq!(vec![1, 2, 3, 4], items(Stack::from_array_partial(a, 4)));
+}
+
+#[test]
+#[should_panic(expected = "No more space left in the stack")]
+fn fails_to_make_stack_from_too_short_array() {
+    let _ = Stack::from_array_partial([1, 2, 3, 4], 5);
+}
+
+#[test]
+fn makes_stack_from_iter_truncating() {
+    let (s, dropped) = Stack::<usize, 4>::from_iter_truncating(0..0);
+    assert!(s.is_empty());
+    assert_eq!(0, dropped);
+    let (s, dropped) = Stack::<usize, 4>::from_iter_truncating(0..3);
+    assert_eq!(vec![0, 1, 2], items(s));
+    assert_eq!(0, dropped);
+    let (s, dropped) = Stack::<usize, 4>::from_iter_truncating(0..4);
+    assert_eq!(vec![0, 1, 2, 3], items(s));
+    assert_eq!(0, dropped);
+    let (s, dropped) = Stack::<usize, 4>::from_iter_truncating(0..5);
+    assert_eq!(vec![0, 1, 2, 3], items(s));
+    assert_eq!(1, dropped);
+}
+
+#[test]
+fn truncates_into_empty_stack() {
+    let (s, dropped) = Stack::<usize, 0>::from_iter_truncating(0..3);
+    assert!(s.is_empty());
+    assert_eq!(3, dropped);
+}
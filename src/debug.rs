@@ -18,27 +18,99 @@
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 // SOFTWARE.
 
-use crate::Stack;
+use crate::{Deque, Detailed, Queue, Stack, StackString};
 use std::fmt;
 use std::fmt::{Debug, Display, Formatter};
 
+/// Write the items, separated by commas, in square brackets, formatting
+/// each of them with the flags of the formatter.
+fn list<'a, V: Display + 'a>(
+    f: &mut Formatter<'_>,
+    items: impl Iterator<Item = &'a V>,
+) -> fmt::Result {
+    f.write_str("[")?;
+    for (i, v) in items.enumerate() {
+        if i > 0 {
+            f.write_str(", ")?;
+        }
+        Display::fmt(v, f)?;
+    }
+    f.write_str("]")
+}
+
+impl<V: Copy, const N: usize> Stack<V, N> {
+    /// Make a view of it, which is `Debug`-formatted as a struct,
+    /// with its length and capacity.
+    #[inline]
+    #[must_use]
+    pub const fn detailed(&self) -> Detailed<'_, V, N> {
+        Detailed { stack: self }
+    }
+}
+
 impl<V: Display + Copy, const N: usize> Display for Stack<V, N> {
+    /// Format it bottom first, or top first in the alternate `{:#}` form.
+    ///
+    /// Width, precision and other flags apply to each element.
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        let mut parts = vec![];
-        for v in self.iter() {
-            parts.push(format!("{v}"));
+        let items = self.items[..self.next].iter();
+        if f.alternate() {
+            list(f, items.rev())
+        } else {
+            list(f, items)
         }
-        f.write_str(format!("[{}]", parts.join(", ").as_str()).as_str())
     }
 }
 
 impl<V: Debug + Copy, const N: usize> Debug for Stack<V, N> {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        let mut parts = vec![];
-        for v in self.iter() {
-            parts.push(format!("{v:?}"));
-        }
-        f.write_str(format!("[{}]", parts.join(", ").as_str()).as_str())
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<V: Debug + Copy, const N: usize> Debug for Detailed<'_, V, N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Stack")
+            .field("len", &self.stack.len())
+            .field("capacity", &N)
+            .field("items", self.stack)
+            .finish()
+    }
+}
+
+impl<V: Display + Copy, const N: usize> Display for Deque<V, N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        list(f, self.iter())
+    }
+}
+
+impl<V: Debug + Copy, const N: usize> Debug for Deque<V, N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<V: Display + Copy, const N: usize> Display for Queue<V, N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.deque, f)
+    }
+}
+
+impl<V: Debug + Copy, const N: usize> Debug for Queue<V, N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Debug::fmt(&self.deque, f)
+    }
+}
+
+impl<const N: usize> Display for StackString<N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(self.as_str(), f)
+    }
+}
+
+impl<const N: usize> Debug for StackString<N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Debug::fmt(self.as_str(), f)
     }
 }
 
@@ -47,7 +119,7 @@ fn debugs_stack() {
     let mut s: Stack<&str, 10> = Stack::new();
     unsafe { s.push_unchecked("one") };
     unsafe { s.push_unchecked("two") };
-    assert_eq!("[\"one\", \"two\"]", format!("{:?}", s));
+    assert_eq!("[\"one\", \"two\"]", format!("{s:?}"));
 }
 
 #[test]
@@ -55,7 +127,7 @@ fn debugs_stack_of_debug_only_type() {
     let mut s: Stack<Option<i32>, 10> = Stack::new();
     unsafe { s.push_unchecked(Some(1)) };
     unsafe { s.push_unchecked(None) };
-    assert_eq!("[Some(1), None]", format!("{:?}", s));
+    assert_eq!("[Some(1), None]", format!("{s:?}"));
 }
 
 #[test]
@@ -63,5 +135,95 @@ fn displays_stack() {
     let mut s: Stack<&str, 10> = Stack::new();
     unsafe { s.push_unchecked("one") };
     unsafe { s.push_unchecked("two") };
-    assert_eq!("[one, two]", format!("{}", s));
+    assert_eq!("[one, two]", format!("{s}"));
+}
+
+#[test]
+fn displays_and_debugs_deque() {
+    let mut d: Deque<&str, 10> = Deque::new();
+    d.push_back("two");
+    d.push_front("one");
+    assert_eq!("[one, two]", format!("{d}"));
+    assert_eq!("[\"one\", \"two\"]", format!("{d:?}"));
+}
+
+#[test]
+fn displays_and_debugs_queue() {
+    let mut q: Queue<u8, 10> = Queue::new();
+    q.push(1);
+    q.push(2);
+    assert_eq!("[1, 2]", format!("{q}"));
+    assert_eq!("[1, 2]", format!("{q:?}"));
+}
+
+#[test]
+fn displays_and_debugs_string() {
+    let s: StackString<8> = "a\"b".parse().unwrap();
+    assert_eq!("a\"b", format!("{s}"));
+    assert_eq!("\"a\\\"b\"", format!("{s:?}"));
+    assert_eq!("  a\"b", format!("{s:>5}"));
+}
+
+#[test]
+fn pretty_debugs_stack() {
+    let mut s: Stack<u8, 10> = Stack::new();
+    s.push(1);
+    s.push(2);
+    assert_eq!("[\n    1,\n    2,\n]", format!("{s:#?}"));
+}
+
+#[test]
+fn debugs_nested_items() {
+    let mut s: Stack<(u8, Option<char>), 2> = Stack::new();
+    s.push((7, Some('x')));
+    assert_eq!("[(7, Some('x'))]", format!("{s:?}"));
+    assert_eq!(
+        "[\n    (\n        7,\n        Some(\n            'x',\n        ),\n    ),\n]",
+        format!("{s:#?}")
+    );
+}
+
+#[test]
+fn debugs_detailed_stack() {
+    let mut s: Stack<u8, 4> = Stack::new();
+    s.push(1);
+    s.push(2);
+    assert_eq!(
+        "Stack { len: 2, capacity: 4, items: [1, 2] }",
+        format!("{:?}", s.detailed())
+    );
+    assert_eq!(
+        "Stack {\n    len: 2,\n    capacity: 4,\n    items: [\n        1,\n        2,\n    ],\n}",
+        format!("{:#?}", s.detailed())
+    );
+}
+
+#[test]
+fn displays_stack_with_flags() {
+    let mut s: Stack<f64, 4> = Stack::new();
+    s.push(1.0);
+    s.push(2.5);
+    assert_eq!("[1.00, 2.50]", format!("{s:.2}"));
+    assert_eq!("[  1.0,   2.5]", format!("{s:5.1}"));
+    assert_eq!("[1, 2.5]", format!("{s}"));
+}
+
+#[test]
+fn displays_stack_top_first() {
+    let mut s: Stack<u8, 4> = Stack::new();
+    s.push(1);
+    s.push(2);
+    s.push(3);
+    assert_eq!("[3, 2, 1]", format!("{s:#}"));
+    assert_eq!("[03, 02, 01]", format!("{s:#02}"));
+    assert_eq!("[]", format!("{:#}", Stack::<u8, 4>::new()));
+}
+
+#[test]
+fn displays_deque_with_flags() {
+    let mut d: Deque<u8, 4> = Deque::new();
+    d.push_back(2);
+    d.push_front(1);
+    assert_eq!("[ 1,  2]", format!("{d:>2}"));
+    assert_eq!("[\n    1,\n    2,\n]", format!("{d:#?}"));
 }
@@ -0,0 +1,469 @@
+// Copyright (c) 2023 Yegor Bugayenko
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{Deque, DequeIntoIter, DequeIter};
+use std::mem::MaybeUninit;
+
+impl<V: Copy, const N: usize> Default for Deque<V, N> {
+    /// Make a default empty [`Deque`].
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<V: Copy, const N: usize> Deque<V, N> {
+    /// Make it.
+    ///
+    /// The size of the deque is defined by the generic argument.
+    #[inline]
+    #[must_use]
+    #[allow(clippy::uninit_assumed_init)]
+    pub const fn new() -> Self {
+        unsafe {
+            Self {
+                head: 0,
+                len: 0,
+                items: MaybeUninit::<[V; N]>::uninit().assume_init(),
+            }
+        }
+    }
+
+    /// The position in the array of the element, counting from the front.
+    #[inline]
+    const fn at(&self, i: usize) -> usize {
+        let p = self.head + i;
+        if p >= N {
+            p - N
+        } else {
+            p
+        }
+    }
+
+    /// Get the capacity.
+    #[inline]
+    #[must_use]
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Push new element to the back.
+    ///
+    /// # Safety
+    ///
+    /// It may lead to undefined behavior, if you go over the boundary.
+    #[inline]
+    pub const unsafe fn push_back_unchecked(&mut self, v: V) {
+        let p = self.at(self.len);
+        self.items.as_mut_ptr().add(p).write(v);
+        self.len += 1;
+    }
+
+    /// Push new element to the back.
+    ///
+    /// # Panics
+    ///
+    /// If there is no more space in the deque, it will panic.
+    #[inline]
+    pub fn push_back(&mut self, v: V) {
+        assert!(self.len < N, "No more space left in the deque");
+        unsafe {
+            self.push_back_unchecked(v);
+        }
+    }
+
+    /// Makes an attempt to push a new element to the back.
+    ///
+    /// # Errors
+    ///
+    /// If there is not enough space in the deque, `Err` is returned.
+    #[inline]
+    pub fn try_push_back(&mut self, v: V) -> Result<(), String> {
+        if self.len < N {
+            self.push_back(v);
+            Ok(())
+        } else {
+            Err(format!("There are no space left in the deque of {N}"))
+        }
+    }
+
+    /// Push new element to the front.
+    ///
+    /// # Safety
+    ///
+    /// It may lead to undefined behavior, if you go over the boundary.
+    #[inline]
+    pub const unsafe fn push_front_unchecked(&mut self, v: V) {
+        self.head = if self.head == 0 { N - 1 } else { self.head - 1 };
+        self.items.as_mut_ptr().add(self.head).write(v);
+        self.len += 1;
+    }
+
+    /// Push new element to the front.
+    ///
+    /// # Panics
+    ///
+    /// If there is no more space in the deque, it will panic.
+    #[inline]
+    pub fn push_front(&mut self, v: V) {
+        assert!(self.len < N, "No more space left in the deque");
+        unsafe {
+            self.push_front_unchecked(v);
+        }
+    }
+
+    /// Makes an attempt to push a new element to the front.
+    ///
+    /// # Errors
+    ///
+    /// If there is not enough space in the deque, `Err` is returned.
+    #[inline]
+    pub fn try_push_front(&mut self, v: V) -> Result<(), String> {
+        if self.len < N {
+            self.push_front(v);
+            Ok(())
+        } else {
+            Err(format!("There are no space left in the deque of {N}"))
+        }
+    }
+
+    /// Pop an element from the back.
+    ///
+    /// # Safety
+    ///
+    /// If there are no items in the deque, the result is undefined.
+    #[inline]
+    pub const unsafe fn pop_back_unchecked(&mut self) -> V {
+        self.len -= 1;
+        self.items.as_ptr().add(self.at(self.len)).read()
+    }
+
+    /// Pop an element from the back.
+    ///
+    /// # Panics
+    ///
+    /// If there are no items in the deque, it will panic.
+    #[inline]
+    pub fn pop_back(&mut self) -> V {
+        assert!(self.len > 0, "No more items left in the deque");
+        unsafe { self.pop_back_unchecked() }
+    }
+
+    /// Pop an element from the back.
+    ///
+    /// # Errors
+    ///
+    /// If there is no more elements left, it will return `Err`.
+    #[inline]
+    pub fn try_pop_back(&mut self) -> Result<V, String> {
+        if self.len == 0 {
+            Err(format!("There are no items left in the deque of {N}"))
+        } else {
+            Ok(self.pop_back())
+        }
+    }
+
+    /// Pop an element from the front.
+    ///
+    /// # Safety
+    ///
+    /// If there are no items in the deque, the result is undefined.
+    #[inline]
+    pub const unsafe fn pop_front_unchecked(&mut self) -> V {
+        let v = self.items.as_ptr().add(self.head).read();
+        self.head = self.at(1);
+        self.len -= 1;
+        v
+    }
+
+    /// Pop an element from the front.
+    ///
+    /// # Panics
+    ///
+    /// If there are no items in the deque, it will panic.
+    #[inline]
+    pub fn pop_front(&mut self) -> V {
+        assert!(self.len > 0, "No more items left in the deque");
+        unsafe { self.pop_front_unchecked() }
+    }
+
+    /// Pop an element from the front.
+    ///
+    /// # Errors
+    ///
+    /// If there is no more elements left, it will return `Err`.
+    #[inline]
+    pub fn try_pop_front(&mut self) -> Result<V, String> {
+        if self.len == 0 {
+            Err(format!("There are no items left in the deque of {N}"))
+        } else {
+            Ok(self.pop_front())
+        }
+    }
+
+    /// Get the element at the front, if any.
+    #[inline]
+    #[must_use]
+    pub const fn front(&self) -> Option<&V> {
+        if self.len == 0 {
+            None
+        } else {
+            Some(&self.items[self.head])
+        }
+    }
+
+    /// Get the element at the back, if any.
+    #[inline]
+    #[must_use]
+    pub const fn back(&self) -> Option<&V> {
+        if self.len == 0 {
+            None
+        } else {
+            Some(&self.items[self.at(self.len - 1)])
+        }
+    }
+
+    /// Clear.
+    #[inline]
+    pub const fn clear(&mut self) {
+        self.head = 0;
+        self.len = 0;
+    }
+
+    /// Is it empty.
+    #[inline]
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Length of it.
+    #[inline]
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Iterate them, from front to back.
+    #[inline]
+    #[must_use]
+    pub const fn iter(&self) -> DequeIter<'_, V, N> {
+        DequeIter {
+            deque: self,
+            pos: 0,
+            end: self.len,
+        }
+    }
+}
+
+impl<'a, V: Copy, const N: usize> Iterator for DequeIter<'a, V, N> {
+    type Item = &'a V;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.pos >= self.end {
+            None
+        } else {
+            let v = &self.deque.items[self.deque.at(self.pos)];
+            self.pos += 1;
+            Some(v)
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = self.end - self.pos;
+        (n, Some(n))
+    }
+}
+
+impl<V: Copy, const N: usize> DoubleEndedIterator for DequeIter<'_, V, N> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.pos >= self.end {
+            None
+        } else {
+            self.end -= 1;
+            Some(&self.deque.items[self.deque.at(self.end)])
+        }
+    }
+}
+
+impl<V: Copy, const N: usize> ExactSizeIterator for DequeIter<'_, V, N> {}
+
+impl<'a, V: Copy, const N: usize> IntoIterator for &'a Deque<V, N> {
+    type Item = &'a V;
+    type IntoIter = DequeIter<'a, V, N>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<V: Copy, const N: usize> Iterator for DequeIntoIter<V, N> {
+    type Item = V;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.deque.try_pop_front().ok()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.deque.len, Some(self.deque.len))
+    }
+}
+
+impl<V: Copy, const N: usize> DoubleEndedIterator for DequeIntoIter<V, N> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.deque.try_pop_back().ok()
+    }
+}
+
+impl<V: Copy, const N: usize> ExactSizeIterator for DequeIntoIter<V, N> {}
+
+impl<V: Copy, const N: usize> IntoIterator for Deque<V, N> {
+    type Item = V;
+    type IntoIter = DequeIntoIter<V, N>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        DequeIntoIter { deque: self }
+    }
+}
+
+#[test]
+fn pushes_and_pops_at_both_ends() {
+    let mut d: Deque<u64, 4> = Deque::new();
+    d.push_back(1);
+    d.push_back(2);
+    d.push_front(0);
+    assert_eq!(Some(&0), d.front());
+    assert_eq!(Some(&2), d.back());
+    assert_eq!(0, d.pop_front());
+    assert_eq!(2, d.pop_back());
+    assert_eq!(1, d.pop_back());
+    assert!(d.is_empty());
+}
+
+#[test]
+fn wraps_around() {
+    let mut d: Deque<usize, 3> = Deque::new();
+    for i in 0..10 {
+        d.push_back(i);
+        if d.len() == 3 {
+            assert_eq!(i - 2, d.pop_front());
+        }
+    }
+    assert_eq!(vec![8, 9], d.iter().copied().collect::<Vec<_>>());
+    assert_eq!(vec![9, 8], d.iter().rev().copied().collect::<Vec<_>>());
+}
+
+#[test]
+fn fails_when_full_or_empty() {
+    let mut d: Deque<u64, 1> = Deque::new();
+    assert!(d.try_pop_front().err().unwrap().contains("left"));
+    assert!(d.try_pop_back().is_err());
+    assert!(d.try_push_front(1).is_ok());
+    assert!(d.try_push_back(2).err().unwrap().contains("space"));
+    assert!(d.try_push_front(2).is_err());
+    assert_eq!(Ok(1), d.try_pop_back());
+}
+
+#[test]
+#[should_panic(expected = "No more space left in the deque")]
+fn panics_on_zero_capacity() {
+    let mut d: Deque<u64, 0> = Deque::new();
+    assert!(d.front().is_none());
+    d.push_front(1);
+}
+
+#[test]
+#[should_panic(expected = "No more items left in the deque")]
+fn panics_on_empty_pop() {
+    let mut d: Deque<u64, 2> = Deque::new();
+    d.pop_front();
+}
+
+#[test]
+fn into_iterates_from_both_ends() {
+    let mut d: Deque<u64, 4> = Deque::new();
+    d.push_front(2);
+    d.push_front(1);
+    d.push_back(3);
+    let mut it = d.into_iter();
+    assert_eq!(3, it.len());
+    assert_eq!(Some(3), it.next_back());
+    assert_eq!(vec![1, 2], it.collect::<Vec<_>>());
+}
+
+#[cfg(test)]
+use proptest::prelude::*;
+
+#[cfg(test)]
+#[derive(Clone, Copy, Debug)]
+enum Op {
+    PushFront(u8),
+    PushBack(u8),
+    PopFront,
+    PopBack,
+}
+
+#[cfg(test)]
+fn op() -> impl Strategy<Value = Op> {
+    prop_oneof![
+        any::<u8>().prop_map(Op::PushFront),
+        any::<u8>().prop_map(Op::PushBack),
+        Just(Op::PopFront),
+        Just(Op::PopBack),
+    ]
+}
+
+#[cfg(test)]
+proptest! {
+    #[test]
+    fn matches_vec_deque(ops in proptest::collection::vec(op(), 0..256)) {
+        let mut d: Deque<u8, 8> = Deque::new();
+        let mut model = std::collections::VecDeque::new();
+        for op in ops {
+            match op {
+                Op::PushFront(v) => {
+                    let pushed = d.try_push_front(v).is_ok();
+                    prop_assert_eq!(model.len() < 8, pushed);
+                    if pushed {
+                        model.push_front(v);
+                    }
+                }
+                Op::PushBack(v) => {
+                    let pushed = d.try_push_back(v).is_ok();
+                    prop_assert_eq!(model.len() < 8, pushed);
+                    if pushed {
+                        model.push_back(v);
+                    }
+                }
+                Op::PopFront => prop_assert_eq!(model.pop_front(), d.try_pop_front().ok()),
+                Op::PopBack => prop_assert_eq!(model.pop_back(), d.try_pop_back().ok()),
+            }
+            prop_assert!(model.iter().eq(d.iter()));
+        }
+    }
+}
@@ -0,0 +1,442 @@
+// Copyright (c) 2023 Yegor Bugayenko
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{DualStack, Left, Right, Side, SideIter};
+use std::mem::MaybeUninit;
+
+impl<V: Copy, const N: usize> Default for DualStack<V, N> {
+    /// Make a default empty [`DualStack`].
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<V: Copy, const N: usize> DualStack<V, N> {
+    /// Make it.
+    ///
+    /// The total size of both stacks is defined by the generic argument.
+    #[inline]
+    #[must_use]
+    #[allow(clippy::uninit_assumed_init)]
+    pub const fn new() -> Self {
+        unsafe {
+            Self {
+                left: 0,
+                right: 0,
+                items: MaybeUninit::<[V; N]>::uninit().assume_init(),
+            }
+        }
+    }
+
+    /// Get the left stack, which grows from the beginning of the array.
+    #[inline]
+    pub const fn left(&mut self) -> Left<'_, V, N> {
+        Side { stack: self }
+    }
+
+    /// Get the right stack, which grows from the end of the array.
+    #[inline]
+    pub const fn right(&mut self) -> Right<'_, V, N> {
+        Side { stack: self }
+    }
+
+    /// Total length of both stacks.
+    #[inline]
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.left + self.right
+    }
+
+    /// Are both stacks empty.
+    #[inline]
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Is there no more space for any of the stacks.
+    #[inline]
+    #[must_use]
+    pub const fn is_full(&self) -> bool {
+        self.len() == N
+    }
+
+    /// Iterate the left stack, from bottom to top.
+    #[inline]
+    pub fn iter_left(&self) -> SideIter<'_, V, true> {
+        SideIter {
+            items: self.items[..self.left].iter(),
+        }
+    }
+
+    /// Iterate the right stack, from bottom to top.
+    #[inline]
+    pub fn iter_right(&self) -> SideIter<'_, V, false> {
+        SideIter {
+            items: self.items[N - self.right..].iter(),
+        }
+    }
+
+    /// Clear both stacks.
+    #[inline]
+    pub const fn clear(&mut self) {
+        self.left = 0;
+        self.right = 0;
+    }
+}
+
+impl<V: Copy, const N: usize, const LEFT: bool> Side<'_, V, N, LEFT> {
+    /// The position in the array of the element, counting from the bottom.
+    #[inline]
+    const fn at(i: usize) -> usize {
+        if LEFT {
+            i
+        } else {
+            N - 1 - i
+        }
+    }
+
+    /// Length of it.
+    #[inline]
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        if LEFT {
+            self.stack.left
+        } else {
+            self.stack.right
+        }
+    }
+
+    /// Is it empty.
+    #[inline]
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Set the length of it.
+    #[inline]
+    const fn resize(&mut self, len: usize) {
+        if LEFT {
+            self.stack.left = len;
+        } else {
+            self.stack.right = len;
+        }
+    }
+
+    /// Push new element into it.
+    ///
+    /// # Safety
+    ///
+    /// It may lead to undefined behavior, if you go over the top
+    /// of the other stack.
+    #[inline]
+    pub const unsafe fn push_unchecked(&mut self, v: V) {
+        let len = self.len();
+        self.stack.items.as_mut_ptr().add(Self::at(len)).write(v);
+        self.resize(len + 1);
+    }
+
+    /// Push new element into it.
+    ///
+    /// # Panics
+    ///
+    /// If the two stacks already meet, it will panic.
+    #[inline]
+    pub fn push(&mut self, v: V) {
+        assert!(!self.stack.is_full(), "No more space left in the stack");
+        unsafe {
+            self.push_unchecked(v);
+        }
+    }
+
+    /// Makes an attempt to push a new element into the stack.
+    ///
+    /// # Errors
+    ///
+    /// If the two stacks already meet, `Err` is returned.
+    #[inline]
+    pub fn try_push(&mut self, v: V) -> Result<(), String> {
+        if self.stack.is_full() {
+            Err(format!("There are no space left in the stack of {N}"))
+        } else {
+            self.push(v);
+            Ok(())
+        }
+    }
+
+    /// Pop a element from it.
+    ///
+    /// # Safety
+    ///
+    /// If there are no items in the stack, the result is undefined.
+    #[inline]
+    pub const unsafe fn pop_unchecked(&mut self) -> V {
+        let len = self.len() - 1;
+        self.resize(len);
+        self.stack.items.as_ptr().add(Self::at(len)).read()
+    }
+
+    /// Pop a element from it.
+    ///
+    /// # Panics
+    ///
+    /// If there are no items in the stack, it will panic.
+    #[inline]
+    pub fn pop(&mut self) -> V {
+        assert!(!self.is_empty(), "No more items left in the stack");
+        unsafe { self.pop_unchecked() }
+    }
+
+    /// Pop a element from it.
+    ///
+    /// # Errors
+    ///
+    /// If there is no more elements left, it will return `Err`.
+    #[inline]
+    pub fn try_pop(&mut self) -> Result<V, String> {
+        if self.is_empty() {
+            Err(format!("There are no items left in the stack of {N}"))
+        } else {
+            Ok(self.pop())
+        }
+    }
+
+    /// Get the top element, without popping it.
+    ///
+    /// # Safety
+    ///
+    /// If there are no items in the stack, the result is undefined.
+    #[inline]
+    #[must_use]
+    pub const unsafe fn peek_unchecked(&self) -> V {
+        self.stack
+            .items
+            .as_ptr()
+            .add(Self::at(self.len() - 1))
+            .read()
+    }
+
+    /// Get the top element, without popping it.
+    ///
+    /// # Panics
+    ///
+    /// If there are no items in the stack, it will panic.
+    #[inline]
+    #[must_use]
+    pub fn peek(&self) -> V {
+        assert!(!self.is_empty(), "No more items left in the stack");
+        unsafe { self.peek_unchecked() }
+    }
+
+    /// Get the top element, without popping it.
+    ///
+    /// # Errors
+    ///
+    /// If there is no elements, it will return `Err`.
+    #[inline]
+    pub fn try_peek(&self) -> Result<V, String> {
+        if self.is_empty() {
+            Err(format!("There are no items left in the stack of {N}"))
+        } else {
+            Ok(self.peek())
+        }
+    }
+
+    /// Clear.
+    #[inline]
+    pub const fn clear(&mut self) {
+        self.resize(0);
+    }
+
+    /// Iterate them, from bottom to top.
+    #[inline]
+    #[must_use]
+    pub fn iter(&self) -> SideIter<'_, V, LEFT> {
+        let items = if LEFT {
+            &self.stack.items[..self.stack.left]
+        } else {
+            &self.stack.items[N - self.stack.right..]
+        };
+        SideIter {
+            items: items.iter(),
+        }
+    }
+}
+
+impl<'a, V: Copy, const N: usize, const LEFT: bool> IntoIterator for &'a Side<'_, V, N, LEFT> {
+    type Item = &'a V;
+    type IntoIter = SideIter<'a, V, LEFT>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, V: Copy, const LEFT: bool> Iterator for SideIter<'a, V, LEFT> {
+    type Item = &'a V;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if LEFT {
+            self.items.next()
+        } else {
+            self.items.next_back()
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.items.size_hint()
+    }
+}
+
+impl<V: Copy, const LEFT: bool> DoubleEndedIterator for SideIter<'_, V, LEFT> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if LEFT {
+            self.items.next_back()
+        } else {
+            self.items.next()
+        }
+    }
+}
+
+impl<V: Copy, const LEFT: bool> ExactSizeIterator for SideIter<'_, V, LEFT> {}
+
+#[test]
+fn pushes_to_both_sides() {
+    let mut s: DualStack<u64, 4> = DualStack::new();
+    s.left().push(1);
+    s.right().push(2);
+    s.left().push(3);
+    assert_eq!(3, s.left().pop());
+    assert_eq!(2, s.right().pop());
+    assert_eq!(1, s.left().pop());
+    assert!(s.is_empty());
+}
+
+#[test]
+fn fails_when_tops_meet() {
+    let mut s: DualStack<u64, 3> = DualStack::new();
+    s.left().push(1);
+    s.right().push(2);
+    s.right().push(3);
+    assert!(s.is_full());
+    assert!(s.left().try_push(4).err().unwrap().contains("space"));
+    assert!(s.right().try_push(4).is_err());
+    s.right().pop();
+    assert!(s.left().try_push(4).is_ok());
+    assert_eq!(vec![1, 4], s.iter_left().copied().collect::<Vec<_>>());
+}
+
+#[test]
+fn uses_whole_array_by_one_side() {
+    let mut s: DualStack<usize, 8> = DualStack::new();
+    for i in 0..8 {
+        s.right().push(i);
+    }
+    assert!(s.left().try_push(0).is_err());
+    assert_eq!(7, s.right().peek());
+    assert_eq!(8, s.right().len());
+}
+
+#[test]
+#[should_panic(expected = "No more space left in the stack")]
+fn panics_when_tops_meet() {
+    let mut s: DualStack<u64, 1> = DualStack::new();
+    s.right().push(1);
+    s.left().push(2);
+}
+
+#[test]
+fn fails_on_empty_side() {
+    let mut s: DualStack<u64, 2> = DualStack::new();
+    s.left().push(1);
+    assert!(s.right().try_pop().err().unwrap().contains("left"));
+    assert!(s.right().try_peek().is_err());
+    assert_eq!(Ok(1), s.left().try_peek());
+}
+
+#[test]
+fn iterates_from_bottom_to_top() {
+    let mut s: DualStack<u64, 8> = DualStack::new();
+    let mut r = s.right();
+    r.push(1);
+    r.push(2);
+    r.push(3);
+    assert_eq!(vec![1, 2, 3], r.iter().copied().collect::<Vec<_>>());
+    assert_eq!(vec![3, 2, 1], r.iter().rev().copied().collect::<Vec<_>>());
+    s.left().push(9);
+    assert_eq!(vec![9], s.left().iter().copied().collect::<Vec<_>>());
+    assert_eq!(3, s.iter_right().len());
+}
+
+#[test]
+fn clears_one_side() {
+    let mut s: DualStack<u64, 4> = DualStack::new();
+    s.left().push(1);
+    s.right().push(2);
+    s.left().clear();
+    assert!(s.left().is_empty());
+    assert_eq!(1, s.len());
+    s.clear();
+    assert!(s.is_empty());
+}
+
+#[cfg(test)]
+use proptest::prelude::*;
+
+#[cfg(test)]
+proptest! {
+    #[test]
+    fn interleaves_with_model(
+        ops in proptest::collection::vec((any::<bool>(), proptest::option::of(any::<u8>())), 0..256)
+    ) {
+        let mut s: DualStack<u8, 16> = DualStack::new();
+        let mut left = vec![];
+        let mut right = vec![];
+        for (to_left, op) in ops {
+            let total = left.len() + right.len();
+            match (to_left, op) {
+                (true, Some(v)) => {
+                    let pushed = s.left().try_push(v).is_ok();
+                    prop_assert_eq!(total < 16, pushed);
+                    if pushed {
+                        left.push(v);
+                    }
+                }
+                (false, Some(v)) => {
+                    let pushed = s.right().try_push(v).is_ok();
+                    prop_assert_eq!(total < 16, pushed);
+                    if pushed {
+                        right.push(v);
+                    }
+                }
+                (true, None) => prop_assert_eq!(left.pop(), s.left().try_pop().ok()),
+                (false, None) => prop_assert_eq!(right.pop(), s.right().try_pop().ok()),
+            }
+            prop_assert_eq!(&left, &s.iter_left().copied().collect::<Vec<_>>());
+            prop_assert_eq!(&right, &s.iter_right().copied().collect::<Vec<_>>());
+        }
+    }
+}
@@ -0,0 +1,171 @@
+// Copyright (c) 2023 Yegor Bugayenko
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::Stack;
+
+#[cfg(feature = "defmt")]
+impl<V: defmt::Format + Copy, const N: usize> defmt::Format for Stack<V, N> {
+    /// Encode it exactly as the slice of its elements, bottom first,
+    /// without any formatting on the device.
+    fn format(&self, f: defmt::Formatter<'_>) {
+        self.items[..self.next].format(f);
+    }
+}
+
+#[cfg(feature = "ufmt")]
+impl<V: ufmt::uDebug + Copy, const N: usize> ufmt::uDebug for Stack<V, N> {
+    fn fmt<W: ufmt::uWrite + ?Sized>(
+        &self,
+        f: &mut ufmt::Formatter<'_, W>,
+    ) -> Result<(), W::Error> {
+        f.debug_list()?.entries(self.iter())?.finish()
+    }
+}
+
+#[cfg(feature = "ufmt")]
+impl<V: ufmt::uDisplay + Copy, const N: usize> ufmt::uDisplay for Stack<V, N> {
+    fn fmt<W: ufmt::uWrite + ?Sized>(
+        &self,
+        f: &mut ufmt::Formatter<'_, W>,
+    ) -> Result<(), W::Error> {
+        f.write_str("[")?;
+        for (i, v) in self.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            ufmt::uDisplay::fmt(v, f)?;
+        }
+        f.write_str("]")
+    }
+}
+
+/// The bytes of all frames logged by defmt.
+#[cfg(all(test, feature = "defmt"))]
+static FRAMES: std::sync::Mutex<Vec<u8>> = std::sync::Mutex::new(Vec::new());
+
+/// The lock, held by a test while it logs.
+#[cfg(all(test, feature = "defmt"))]
+static LOGGING: std::sync::Mutex<()> = std::sync::Mutex::new(());
+
+#[cfg(all(test, feature = "defmt"))]
+#[defmt::global_logger]
+struct Logger;
+
+#[cfg(all(test, feature = "defmt"))]
+unsafe impl defmt::Logger for Logger {
+    fn acquire() {}
+
+    unsafe fn flush() {}
+
+    unsafe fn release() {}
+
+    unsafe fn write(bytes: &[u8]) {
+        FRAMES.lock().unwrap().extend_from_slice(bytes);
+    }
+}
+
+#[cfg(all(test, feature = "defmt"))]
+defmt::timestamp!("");
+
+/// Log the value with `{}`, always from the same place, so that the
+/// interned format string is the same, and return the raw frame.
+#[cfg(all(test, feature = "defmt"))]
+fn logged<T: defmt::Format>(v: T) -> Vec<u8> {
+    let _lock = LOGGING.lock().unwrap();
+    FRAMES.lock().unwrap().clear();
+    defmt::println!("{}", v);
+    std::mem::take(&mut *FRAMES.lock().unwrap())
+}
+
+/// Split the frame into the index of the interned format string,
+/// which every frame starts with, and the encoded arguments.
+#[cfg(all(test, feature = "defmt"))]
+const fn split(frame: &[u8]) -> (&[u8], &[u8]) {
+    frame.split_at(2)
+}
+
+#[cfg(all(test, feature = "defmt"))]
+fn contains(frame: &[u8], part: &[u8]) -> bool {
+    frame.windows(part.len()).any(|w| w == part)
+}
+
+#[test]
+#[cfg(feature = "defmt")]
+fn encodes_as_slice() {
+    let s: Stack<u8, 4> = Stack::from_array([23, 42]);
+    let frame = logged(s);
+    let slice = logged(&[23u8, 42][..]);
+    let reversed = logged(&[42u8, 23][..]);
+    assert_eq!(split(&slice).0, split(&frame).0);
+    assert!(contains(split(&frame).1, split(&slice).1));
+    assert!(!contains(split(&frame).1, split(&reversed).1));
+}
+
+#[test]
+#[cfg(feature = "defmt")]
+fn encodes_same_as_slice() {
+    let s: Stack<u32, 8> = Stack::from_array([1, 70000]);
+    let frame = logged(s);
+    let slice = logged(&[1u32, 70000][..]);
+    assert_eq!(split(&slice).0, split(&frame).0);
+    assert!(contains(split(&frame).1, split(&slice).1));
+}
+
+#[test]
+#[cfg(feature = "defmt")]
+fn encodes_empty_stack() {
+    let s: Stack<u64, 4> = Stack::new();
+    let frame = logged(s);
+    let slice = logged(&[0u64; 0][..]);
+    let one = logged(&[0u64][..]);
+    assert_eq!(split(&slice).0, split(&frame).0);
+    assert!(contains(split(&frame).1, split(&slice).1));
+    assert!(!contains(split(&frame).1, split(&one).1));
+}
+
+#[test]
+#[cfg(feature = "ufmt")]
+fn udebugs_stack() {
+    let s: Stack<u8, 4> = Stack::from_array([1, 2]);
+    let mut out = String::new();
+    ufmt::uwrite!(out, "{:?}", s).unwrap();
+    assert_eq!("[1, 2]", out);
+}
+
+#[test]
+#[cfg(feature = "ufmt")]
+fn pretty_udebugs_stack() {
+    let s: Stack<u8, 4> = Stack::from_array([1, 2]);
+    let mut out = String::new();
+    ufmt::uwrite!(out, "{:#?}", s).unwrap();
+    assert_eq!(format!("{s:#?}"), out);
+}
+
+#[test]
+#[cfg(feature = "ufmt")]
+fn udisplays_stack() {
+    let s: Stack<&str, 4> = Stack::from_array(["one", "two"]);
+    let mut out = String::new();
+    ufmt::uwrite!(out, "{}", s).unwrap();
+    assert_eq!("[one, two]", out);
+    out.clear();
+    ufmt::uwrite!(out, "{}", Stack::<u8, 4>::new()).unwrap();
+    assert_eq!("[]", out);
+}
@@ -0,0 +1,211 @@
+// Copyright (c) 2023 Yegor Bugayenko
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{Frame, Mark, Stack};
+use std::ops::{Deref, DerefMut};
+
+impl<V: Copy, const N: usize> Stack<V, N> {
+    /// Remember the current height of the stack.
+    #[inline]
+    #[must_use]
+    pub const fn mark(&self) -> Mark {
+        Mark { height: self.next }
+    }
+
+    /// Drop all elements above the mark.
+    ///
+    /// # Panics
+    ///
+    /// If the stack is already lower than the mark, it will panic.
+    #[inline]
+    pub fn unwind_to(&mut self, mark: Mark) {
+        assert!(
+            mark.height <= self.next,
+            "The stack is already below the mark"
+        );
+        self.next = mark.height;
+    }
+
+    /// Get all elements above the mark, from bottom to top.
+    ///
+    /// # Panics
+    ///
+    /// If the stack is already lower than the mark, it will panic.
+    #[inline]
+    #[must_use]
+    pub fn frame(&self, mark: Mark) -> &[V] {
+        assert!(
+            mark.height <= self.next,
+            "The stack is already below the mark"
+        );
+        &self.items[mark.height..self.next]
+    }
+
+    /// Open a new frame, which will unwind the stack back to
+    /// its current height when dropped.
+    #[inline]
+    #[must_use]
+    pub const fn enter(&mut self) -> Frame<'_, V, N> {
+        let mark = self.mark();
+        Frame { stack: self, mark }
+    }
+}
+
+impl<V: Copy, const N: usize> Frame<'_, V, N> {
+    /// Get the mark the frame will unwind to.
+    #[inline]
+    #[must_use]
+    pub const fn mark(&self) -> Mark {
+        self.mark
+    }
+
+    /// Get all elements pushed inside the frame, from bottom to top.
+    ///
+    /// If the stack was already cleared or popped below the mark,
+    /// the result is empty.
+    #[inline]
+    #[must_use]
+    pub fn items(&self) -> &[V] {
+        if self.stack.next < self.mark.height {
+            return &[];
+        }
+        self.stack.frame(self.mark)
+    }
+}
+
+impl<V: Copy, const N: usize> Deref for Frame<'_, V, N> {
+    type Target = Stack<V, N>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.stack
+    }
+}
+
+impl<V: Copy, const N: usize> DerefMut for Frame<'_, V, N> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.stack
+    }
+}
+
+impl<V: Copy, const N: usize> Drop for Frame<'_, V, N> {
+    /// Unwind the stack, unless it is already below the mark.
+    #[inline]
+    fn drop(&mut self) {
+        if self.stack.next > self.mark.height {
+            self.stack.next = self.mark.height;
+        }
+    }
+}
+
+#[test]
+fn marks_and_unwinds() {
+    let mut s: Stack<u64, 8> = Stack::new();
+    s.push(1);
+    let m = s.mark();
+    s.push(2);
+    s.push(3);
+    s.unwind_to(m);
+    assert_eq!(1, s.len());
+    assert_eq!(1, s.pop());
+}
+
+#[test]
+fn unwinds_to_same_height() {
+    let mut s: Stack<u64, 8> = Stack::new();
+    s.push(1);
+    let m = s.mark();
+    s.unwind_to(m);
+    assert_eq!(1, s.len());
+}
+
+#[test]
+#[should_panic(expected = "The stack is already below the mark")]
+fn panics_when_unwinding_above_height() {
+    let mut s: Stack<u64, 8> = Stack::new();
+    s.push(1);
+    let m = s.mark();
+    s.pop();
+    s.unwind_to(m);
+}
+
+#[test]
+fn reads_frame_above_mark() {
+    let mut s: Stack<u64, 8> = Stack::new();
+    s.push(1);
+    let m = s.mark();
+    assert!(s.frame(m).is_empty());
+    s.push(2);
+    s.push(3);
+    assert_eq!(&[2, 3], s.frame(m));
+}
+
+#[test]
+fn frame_unwinds_on_drop() {
+    let mut s: Stack<u64, 8> = Stack::new();
+    s.push(1);
+    {
+        let mut f = s.enter();
+        f.push(2);
+        f.push(3);
+        assert_eq!(&[2, 3], f.items());
+        assert_eq!(3, f.len());
+    }
+    assert_eq!(1, s.len());
+    assert_eq!(1, s.pop());
+}
+
+#[test]
+fn nested_frames_unwind_in_order() {
+    let mut s: Stack<u64, 8> = Stack::new();
+    let mut outer = s.enter();
+    outer.push(1);
+    {
+        let mut inner = outer.enter();
+        inner.push(2);
+        assert_eq!(&[2], inner.items());
+    }
+    assert_eq!(&[1], outer.items());
+    drop(outer);
+    assert!(s.is_empty());
+}
+
+#[test]
+fn frame_survives_popping_below_mark() {
+    let mut s: Stack<u64, 8> = Stack::new();
+    s.push(1);
+    s.push(2);
+    {
+        let mut f = s.enter();
+        f.clear();
+    }
+    assert!(s.is_empty());
+}
+
+#[test]
+fn frame_items_are_empty_below_mark() {
+    let mut s: Stack<u64, 8> = Stack::new();
+    s.push(1);
+    let mut f = s.enter();
+    f.push(2);
+    f.clear();
+    assert!(f.items().is_empty());
+}
@@ -0,0 +1,200 @@
+// Copyright (c) 2023 Yegor Bugayenko
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+#[cfg(any(
+    test,
+    feature = "proptest",
+    feature = "quickcheck",
+    feature = "arbitrary"
+))]
+use crate::Stack;
+
+#[cfg(feature = "proptest")]
+impl<V: proptest::arbitrary::Arbitrary + Copy, const N: usize> proptest::arbitrary::Arbitrary
+    for Stack<V, N>
+{
+    type Parameters = V::Parameters;
+    type Strategy =
+        proptest::strategy::Map<proptest::collection::VecStrategy<V::Strategy>, fn(Vec<V>) -> Self>;
+
+    /// Make a strategy of stacks with up to `N` arbitrary elements.
+    fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+        proptest::strategy::Strategy::prop_map(
+            proptest::collection::vec(V::arbitrary_with(args), 0..=N),
+            Self::from_vec,
+        )
+    }
+}
+
+#[cfg(feature = "quickcheck")]
+impl<V: quickcheck::Arbitrary + Copy, const N: usize> quickcheck::Arbitrary for Stack<V, N> {
+    /// Make a stack with up to `N` arbitrary elements.
+    fn arbitrary(g: &mut quickcheck::Gen) -> Self {
+        let len = <usize as quickcheck::Arbitrary>::arbitrary(g) % (N + 1);
+        let mut s = Self::new();
+        for _ in 0..len {
+            unsafe { s.push_unchecked(V::arbitrary(g)) };
+        }
+        s
+    }
+
+    /// Shrink it, by shrinking the vector of its elements.
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        let items: Vec<V> = self.iter().copied().collect();
+        Box::new(items.shrink().map(Self::from_vec))
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a, V: arbitrary::Arbitrary<'a> + Copy, const N: usize> arbitrary::Arbitrary<'a>
+    for Stack<V, N>
+{
+    /// Make a stack with up to `N` arbitrary elements, never more.
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let len = u.arbitrary_len::<V>()?.min(N);
+        let mut s = Self::new();
+        for _ in 0..len {
+            unsafe { s.push_unchecked(V::arbitrary(u)?) };
+        }
+        Ok(s)
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        (0, V::size_hint(depth).1.and_then(|m| m.checked_mul(N)))
+    }
+}
+
+#[cfg(test)]
+use proptest::prelude::*;
+
+#[cfg(test)]
+#[derive(Clone, Copy, Debug)]
+enum Op {
+    Push(u8),
+    Pop,
+    TryPush(u8),
+    TryPop,
+    Clear,
+    Iter,
+    Clone,
+}
+
+#[cfg(test)]
+fn op() -> impl Strategy<Value = Op> {
+    prop_oneof![
+        any::<u8>().prop_map(Op::Push),
+        Just(Op::Pop),
+        any::<u8>().prop_map(Op::TryPush),
+        Just(Op::TryPop),
+        Just(Op::Clear),
+        Just(Op::Iter),
+        Just(Op::Clone),
+    ]
+}
+
+#[cfg(test)]
+proptest! {
+    #[test]
+    fn behaves_like_vec(ops in proptest::collection::vec(op(), 0..256)) {
+        let mut s: Stack<u8, 8> = Stack::new();
+        let mut model: Vec<u8> = vec![];
+        for op in ops {
+            match op {
+                Op::Push(v) => {
+                    if model.len() < 8 {
+                        s.push(v);
+                        model.push(v);
+                    }
+                }
+                Op::Pop => {
+                    if let Some(v) = model.pop() {
+                        prop_assert_eq!(v, s.pop());
+                    }
+                }
+                Op::TryPush(v) => {
+                    let pushed = s.try_push(v).is_ok();
+                    prop_assert_eq!(model.len() < 8, pushed);
+                    if pushed {
+                        model.push(v);
+                    }
+                }
+                Op::TryPop => prop_assert_eq!(model.pop(), s.try_pop().ok()),
+                Op::Clear => {
+                    s.clear();
+                    model.clear();
+                }
+                Op::Iter => prop_assert!(model.iter().eq(s.iter())),
+                Op::Clone => s = s.clone(),
+            }
+            prop_assert_eq!(model.len(), s.len());
+        }
+        prop_assert_eq!(model, s.into_iter().collect::<Vec<_>>());
+    }
+}
+
+#[cfg(all(test, feature = "proptest"))]
+proptest! {
+    #[test]
+    fn makes_arbitrary_stacks(s in any::<Stack<u16, 4>>()) {
+        prop_assert!(s.len() <= 4);
+        prop_assert_eq!(s.len(), s.iter().count());
+    }
+}
+
+#[test]
+#[cfg(feature = "quickcheck")]
+fn makes_quickcheck_stacks() {
+    fn fits(s: Stack<u16, 4>) -> bool {
+        s.len() <= 4 && s.len() == s.into_iter().count()
+    }
+    quickcheck::quickcheck(fits as fn(Stack<u16, 4>) -> bool);
+}
+
+#[test]
+#[cfg(feature = "quickcheck")]
+fn shrinks_quickcheck_stacks() {
+    use quickcheck::Arbitrary;
+    let s: Stack<u16, 4> = Stack::from_vec(vec![5, 7]);
+    assert!(s.shrink().all(|t| t.len() <= 2));
+    assert!(s.shrink().any(|t| t.is_empty()));
+}
+
+#[test]
+#[cfg(feature = "arbitrary")]
+fn makes_arbitrary_stacks_from_bytes() {
+    use arbitrary::Unstructured;
+    let bytes: Vec<u8> = (0..=255).collect();
+    let mut u = Unstructured::new(&bytes);
+    for _ in 0..16 {
+        let s = u.arbitrary::<Stack<u32, 4>>().unwrap();
+        assert!(s.len() <= 4);
+    }
+}
+
+#[test]
+#[cfg(feature = "arbitrary")]
+fn makes_full_stack_from_long_input() {
+    use arbitrary::{Arbitrary, Unstructured};
+    let bytes = [7u8; 1024];
+    let s =
+        <Stack<u8, 8> as Arbitrary<'_>>::arbitrary_take_rest(Unstructured::new(&bytes)).unwrap();
+    assert_eq!(8, s.len());
+    assert!(s.iter().all(|v| *v == 7));
+}
@@ -0,0 +1,312 @@
+// Copyright (c) 2023 Yegor Bugayenko
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{Heap, Stack};
+use std::ops::Deref;
+
+impl<V: Ord + Copy, const N: usize> Default for Heap<V, N> {
+    /// Make a default empty [`Heap`].
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<V: Ord + Copy, const N: usize> Deref for Heap<V, N> {
+    type Target = Stack<V, N>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.stack
+    }
+}
+
+impl<V: Ord + Copy, const N: usize> From<[V; N]> for Heap<V, N> {
+    /// Heapify the array.
+    #[inline]
+    fn from(items: [V; N]) -> Self {
+        Self::from_array(items)
+    }
+}
+
+impl<V: Ord + Copy, const N: usize> Heap<V, N> {
+    /// Make it.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            stack: Stack::new(),
+        }
+    }
+
+    /// Make it full, from all elements of the array, in linear time.
+    #[inline]
+    #[must_use]
+    pub fn from_array(items: [V; N]) -> Self {
+        let mut h = Self {
+            stack: Stack { next: N, items },
+        };
+        for i in (0..N / 2).rev() {
+            h.sift_down(i, N);
+        }
+        h
+    }
+
+    /// Move the element up, until its parent is not smaller.
+    #[inline]
+    fn sift_up(&mut self, mut i: usize) {
+        let items = &mut self.stack.items;
+        while i > 0 {
+            let parent = (i - 1) / 2;
+            if items[parent] >= items[i] {
+                break;
+            }
+            items.swap(parent, i);
+            i = parent;
+        }
+    }
+
+    /// Move the element down, until its children are not larger,
+    /// considering only the first `end` elements.
+    #[inline]
+    fn sift_down(&mut self, mut i: usize, end: usize) {
+        let items = &mut self.stack.items;
+        loop {
+            let left = 2 * i + 1;
+            if left >= end {
+                break;
+            }
+            let right = left + 1;
+            let child = if right < end && items[right] > items[left] {
+                right
+            } else {
+                left
+            };
+            if items[i] >= items[child] {
+                break;
+            }
+            items.swap(i, child);
+            i = child;
+        }
+    }
+
+    /// Push new element into it.
+    ///
+    /// # Safety
+    ///
+    /// It may lead to undefined behavior, if you go over the boundary.
+    #[inline]
+    pub unsafe fn push_unchecked(&mut self, v: V) {
+        self.stack.push_unchecked(v);
+        self.sift_up(self.stack.next - 1);
+    }
+
+    /// Push new element into it.
+    ///
+    /// # Panics
+    ///
+    /// If there is no more space in the heap, it will panic.
+    #[inline]
+    pub fn push(&mut self, v: V) {
+        assert!(self.stack.next < N, "No more space left in the heap");
+        unsafe {
+            self.push_unchecked(v);
+        }
+    }
+
+    /// Makes an attempt to push a new element into the heap.
+    ///
+    /// # Errors
+    ///
+    /// If there is not enough space in the heap, `Err` is returned.
+    #[inline]
+    pub fn try_push(&mut self, v: V) -> Result<(), String> {
+        if self.stack.next < N {
+            self.push(v);
+            Ok(())
+        } else {
+            Err(format!("There are no space left in the heap of {N}"))
+        }
+    }
+
+    /// Pop the largest element from it.
+    ///
+    /// # Safety
+    ///
+    /// If there are no items in the heap, the result is undefined.
+    #[inline]
+    pub unsafe fn pop_unchecked(&mut self) -> V {
+        let last = self.stack.pop_unchecked();
+        if self.stack.next == 0 {
+            return last;
+        }
+        let top = std::mem::replace(&mut self.stack.items[0], last);
+        self.sift_down(0, self.stack.next);
+        top
+    }
+
+    /// Pop the largest element from it.
+    ///
+    /// # Panics
+    ///
+    /// If there are no items in the heap, it will panic.
+    #[inline]
+    pub fn pop(&mut self) -> V {
+        assert!(self.stack.next > 0, "No more items left in the heap");
+        unsafe { self.pop_unchecked() }
+    }
+
+    /// Pop the largest element from it.
+    ///
+    /// # Errors
+    ///
+    /// If there is no more elements left, it will return `Err`.
+    #[inline]
+    pub fn try_pop(&mut self) -> Result<V, String> {
+        if self.stack.next == 0 {
+            Err(format!("There are no items left in the heap of {N}"))
+        } else {
+            Ok(self.pop())
+        }
+    }
+
+    /// Get the largest element, if any.
+    #[inline]
+    #[must_use]
+    pub const fn peek(&self) -> Option<&V> {
+        if self.stack.next == 0 {
+            None
+        } else {
+            Some(&self.stack.items[0])
+        }
+    }
+
+    /// Clear.
+    #[inline]
+    pub const fn clear(&mut self) {
+        self.stack.clear();
+    }
+
+    /// Turn it into a [`Stack`], sorted in ascending order, so that
+    /// the largest element is on top.
+    #[inline]
+    #[must_use]
+    pub fn into_sorted(mut self) -> Stack<V, N> {
+        let mut end = self.stack.next;
+        while end > 1 {
+            end -= 1;
+            self.stack.items.swap(0, end);
+            self.sift_down(0, end);
+        }
+        self.stack
+    }
+}
+
+#[cfg(test)]
+use std::cmp::Reverse;
+
+#[test]
+fn pops_largest_first() {
+    let mut h: Heap<u64, 8> = Heap::new();
+    for v in [3, 1, 4, 1, 5, 9, 2, 6] {
+        h.push(v);
+    }
+    assert_eq!(Some(&9), h.peek());
+    let popped: Vec<u64> = (0..8).map(|_| h.pop()).collect();
+    assert_eq!(vec![9, 6, 5, 4, 3, 2, 1, 1], popped);
+    assert!(h.peek().is_none());
+}
+
+#[test]
+fn pops_smallest_first_when_reversed() {
+    let mut h: Heap<Reverse<u64>, 4> = Heap::new();
+    h.push(Reverse(5));
+    h.push(Reverse(2));
+    h.push(Reverse(8));
+    assert_eq!(Reverse(2), h.pop());
+    assert_eq!(Reverse(5), h.pop());
+}
+
+#[test]
+fn fails_when_full_or_empty() {
+    let mut h: Heap<u64, 1> = Heap::new();
+    assert!(h.try_pop().err().unwrap().contains("left"));
+    assert!(h.try_push(1).is_ok());
+    assert!(h.try_push(2).err().unwrap().contains("space"));
+    assert_eq!(Ok(1), h.try_pop());
+}
+
+#[test]
+#[should_panic(expected = "No more space left in the heap")]
+fn panics_when_full() {
+    let mut h: Heap<u64, 0> = Heap::new();
+    h.push(1);
+}
+
+#[test]
+fn heapifies_array() {
+    let mut h = Heap::from([5, 3, 8, 1, 9, 2]);
+    assert_eq!(6, h.len());
+    assert_eq!(9, h.pop());
+    assert_eq!(8, h.pop());
+    assert_eq!(5, h.pop());
+}
+
+#[test]
+fn sorts_into_stack() {
+    let mut h: Heap<u64, 8> = Heap::new();
+    for v in [7, 3, 9, 3, 1] {
+        h.push(v);
+    }
+    let s = h.into_sorted();
+    assert_eq!(vec![1, 3, 3, 7, 9], s.into_iter().collect::<Vec<_>>());
+}
+
+#[test]
+fn sorts_empty_heap() {
+    let h: Heap<u64, 4> = Heap::new();
+    assert!(h.into_sorted().is_empty());
+}
+
+#[cfg(test)]
+use proptest::prelude::*;
+
+#[cfg(test)]
+proptest! {
+    #[test]
+    fn matches_binary_heap(
+        ops in proptest::collection::vec(proptest::option::of(0..16u8), 0..256)
+    ) {
+        let mut h: Heap<u8, 32> = Heap::new();
+        let mut model = std::collections::BinaryHeap::new();
+        for op in ops {
+            if let Some(v) = op {
+                let pushed = h.try_push(v).is_ok();
+                prop_assert_eq!(model.len() < 32, pushed);
+                if pushed {
+                    model.push(v);
+                }
+            } else {
+                prop_assert_eq!(model.pop(), h.try_pop().ok());
+            }
+            prop_assert_eq!(model.peek(), h.peek());
+        }
+    }
+}
@@ -0,0 +1,186 @@
+// Copyright (c) 2023 Yegor Bugayenko
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+#[cfg(feature = "std")]
+use crate::Reader;
+use crate::Stack;
+use std::fmt;
+#[cfg(feature = "std")]
+use std::io;
+use std::ptr;
+
+impl<const N: usize> fmt::Write for Stack<u8, N> {
+    /// Push all bytes of the string, failing if they don't fit.
+    #[inline]
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        if s.len() > N - self.next {
+            return Err(fmt::Error);
+        }
+        unsafe {
+            ptr::copy_nonoverlapping(s.as_ptr(), self.items.as_mut_ptr().add(self.next), s.len());
+        }
+        self.next += s.len();
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<const N: usize> io::Write for Stack<u8, N> {
+    /// Push all bytes, failing if they don't fit.
+    ///
+    /// A short write never happens: just like [`fmt::Write::write_str`],
+    /// if the buffer doesn't fit entirely, nothing is written and
+    /// [`io::ErrorKind::WriteZero`] is returned.
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if buf.len() > N - self.next {
+            return Err(io::Error::new(
+                io::ErrorKind::WriteZero,
+                format!("There are no space left in the stack of {N}"),
+            ));
+        }
+        unsafe {
+            ptr::copy_nonoverlapping(
+                buf.as_ptr(),
+                self.items.as_mut_ptr().add(self.next),
+                buf.len(),
+            );
+        }
+        self.next += buf.len();
+        Ok(buf.len())
+    }
+
+    #[inline]
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<const N: usize> Stack<u8, N> {
+    /// Read bytes from the bottom of it.
+    #[inline]
+    pub const fn reader(&mut self) -> Reader<'_, N> {
+        Reader {
+            stack: self,
+            pos: 0,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<const N: usize> io::Read for Reader<'_, N> {
+    #[inline]
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = buf.len().min(self.stack.next - self.pos);
+        buf[..n].copy_from_slice(&self.stack.items[self.pos..self.pos + n]);
+        self.pos += n;
+        Ok(n)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<const N: usize> Drop for Reader<'_, N> {
+    /// Remove all bytes read, moving the rest to the bottom.
+    #[inline]
+    fn drop(&mut self) {
+        let rest = self.stack.next - self.pos;
+        unsafe {
+            let p = self.stack.items.as_mut_ptr();
+            ptr::copy(p.add(self.pos), p, rest);
+        }
+        self.stack.next = rest;
+    }
+}
+
+#[test]
+fn writes_formatted_output() {
+    use std::fmt::Write;
+    let mut s: Stack<u8, 16> = Stack::new();
+    let id = "id";
+    write!(s, "{id}-{:02x}", 255).unwrap();
+    assert_eq!(b"id-ff", &s.items[..s.len()]);
+}
+
+#[test]
+fn rejects_formatted_overflow() {
+    use std::fmt::Write;
+    let mut s: Stack<u8, 4> = Stack::new();
+    s.write_str("ab").unwrap();
+    assert!(s.write_str("cde").is_err());
+    assert_eq!(2, s.len());
+    assert!(write!(s, "{}", 12345).is_err());
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn writes_protocol_frames() {
+    use std::io::{Read, Write};
+    let mut s: Stack<u8, 32> = Stack::new();
+    for payload in [b"ping".as_slice(), b"hello".as_slice()] {
+        s.write_all(&u16::try_from(payload.len()).unwrap().to_be_bytes())
+            .unwrap();
+        s.write_all(payload).unwrap();
+    }
+    assert_eq!(13, s.len());
+    let mut r = s.reader();
+    let mut len = [0; 2];
+    r.read_exact(&mut len).unwrap();
+    let mut payload = vec![0; usize::from(u16::from_be_bytes(len))];
+    r.read_exact(&mut payload).unwrap();
+    assert_eq!(b"ping", payload.as_slice());
+    drop(r);
+    assert_eq!(7, s.len());
+    let mut rest = vec![];
+    s.reader().read_to_end(&mut rest).unwrap();
+    assert_eq!(b"\x00\x05hello", rest.as_slice());
+    assert!(s.is_empty());
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn fails_with_write_zero() {
+    use std::io::Write;
+    let mut s: Stack<u8, 3> = Stack::new();
+    assert_eq!(
+        std::io::ErrorKind::WriteZero,
+        s.write(b"abcd").unwrap_err().kind()
+    );
+    assert!(s.is_empty());
+    assert_eq!(3, s.write(b"abc").unwrap());
+    assert_eq!(
+        std::io::ErrorKind::WriteZero,
+        s.write(b"e").unwrap_err().kind()
+    );
+    assert_eq!(0, s.write(b"").unwrap());
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn never_writes_truncated_frame() {
+    use std::io::Write;
+    let mut s: Stack<u8, 8> = Stack::new();
+    s.write_all(b"head").unwrap();
+    assert_eq!(
+        std::io::ErrorKind::WriteZero,
+        s.write_all(b"frame").unwrap_err().kind()
+    );
+    assert_eq!(b"head", &s.items[..s.len()]);
+}
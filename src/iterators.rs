@@ -50,6 +50,16 @@ impl<V: Copy, const N: usize> IntoIterator for Stack<V, N> {
     }
 }
 
+impl<'a, V: Copy, const N: usize> IntoIterator for &'a Stack<V, N> {
+    type Item = &'a V;
+    type IntoIter = Iter<'a, V, N>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
 impl<'a, V: Copy, const N: usize> Iterator for Iter<'a, V, N> {
     type Item = &'a V;
 
@@ -67,7 +77,7 @@ impl<'a, V: Copy, const N: usize> Iterator for Iter<'a, V, N> {
     }
 }
 
-impl<'a, V: Copy + 'a, const N: usize> Stack<V, N> {
+impl<V: Copy, const N: usize> Stack<V, N> {
     /// Iterate them.
     #[inline]
     pub const fn iter(&self) -> Iter<'_, V, N> {
@@ -87,7 +97,7 @@ fn push_and_iterate() {
     unsafe { p.push_unchecked(2) };
     unsafe { p.push_unchecked(3) };
     let mut sum = 0;
-    for x in p.iter() {
+    for x in &p {
         sum += x;
     }
     assert_eq!(6, sum);
@@ -0,0 +1,495 @@
+// Copyright (c) 2023 Yegor Bugayenko
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{Checkpoint, Journal, Stack};
+use std::ops::Deref;
+
+impl<V: Copy, const N: usize, const L: usize> Default for Journal<V, N, L> {
+    /// Make a default empty [`Journal`].
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<V: Copy, const N: usize, const L: usize> From<Stack<V, N>> for Journal<V, N, L> {
+    /// Wrap an existing [`Stack`].
+    #[inline]
+    fn from(stack: Stack<V, N>) -> Self {
+        Self {
+            stack,
+            log: Stack::new(),
+            guard: 0,
+            base: 0,
+            depth: 0,
+        }
+    }
+}
+
+impl<V: Copy, const N: usize, const L: usize> Deref for Journal<V, N, L> {
+    type Target = Stack<V, N>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.stack
+    }
+}
+
+impl<V: Copy, const N: usize, const L: usize> Journal<V, N, L> {
+    /// Make it.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            stack: Stack::new(),
+            log: Stack::new(),
+            guard: 0,
+            base: 0,
+            depth: 0,
+        }
+    }
+
+    /// Take the stack out of it, forgetting all checkpoints.
+    #[inline]
+    #[must_use]
+    pub const fn into_inner(self) -> Stack<V, N> {
+        self.stack
+    }
+
+    /// Does the next push need a log entry?
+    ///
+    /// It does, if it overwrites a slot below the guard, which was not
+    /// yet logged since the last checkpoint.
+    #[inline]
+    const fn overwrites(&self) -> bool {
+        let pos = self.stack.next;
+        if pos >= self.guard {
+            return false;
+        }
+        let mut i = self.base;
+        while i < self.log.next {
+            if self.log.items[i].0 == pos {
+                return false;
+            }
+            i += 1;
+        }
+        true
+    }
+
+    /// Push new element into it.
+    ///
+    /// # Safety
+    ///
+    /// It may lead to undefined behavior, if you go over the boundary
+    /// of the stack or of the log.
+    #[inline]
+    pub const unsafe fn push_unchecked(&mut self, v: V) {
+        if self.overwrites() {
+            let pos = self.stack.next;
+            self.log.push_unchecked((pos, self.stack.items[pos]));
+        }
+        self.stack.push_unchecked(v);
+    }
+
+    /// Push new element into it.
+    ///
+    /// # Panics
+    ///
+    /// If there is no more space in the stack or in the log, it will panic.
+    #[inline]
+    pub fn push(&mut self, v: V) {
+        assert!(self.stack.next < N, "No more space left in the stack");
+        assert!(
+            !self.overwrites() || self.log.next < L,
+            "No more space left in the log"
+        );
+        unsafe {
+            self.push_unchecked(v);
+        }
+    }
+
+    /// Makes an attempt to push a new element into the stack.
+    ///
+    /// # Errors
+    ///
+    /// If there is not enough space in the stack or in the log,
+    /// `Err` is returned.
+    #[inline]
+    pub fn try_push(&mut self, v: V) -> Result<(), String> {
+        if self.stack.next >= N {
+            Err(format!("There are no space left in the stack of {N}"))
+        } else if self.overwrites() && self.log.next >= L {
+            Err(format!("There are no space left in the log of {L}"))
+        } else {
+            self.push(v);
+            Ok(())
+        }
+    }
+
+    /// Pop a element from it.
+    ///
+    /// # Safety
+    ///
+    /// If there are no items in the array, the result is undefined.
+    #[inline]
+    pub const unsafe fn pop_unchecked(&mut self) -> V {
+        self.stack.pop_unchecked()
+    }
+
+    /// Pop a element from it.
+    ///
+    /// # Panics
+    ///
+    /// If there are no items in the array, it will panic.
+    #[inline]
+    pub fn pop(&mut self) -> V {
+        self.stack.pop()
+    }
+
+    /// Pop a element from it.
+    ///
+    /// # Errors
+    ///
+    /// If there is no more elements left, it will return `Err`.
+    #[inline]
+    pub fn try_pop(&mut self) -> Result<V, String> {
+        self.stack.try_pop()
+    }
+
+    /// Clear.
+    #[inline]
+    pub const fn clear(&mut self) {
+        self.stack.clear();
+    }
+
+    /// Remember the current state, in order to roll back to it later.
+    ///
+    /// Checkpoints may be nested, but must be closed, either by
+    /// [`Journal::rollback`] or by [`Journal::commit`], in reverse order.
+    #[inline]
+    #[must_use]
+    pub const fn checkpoint(&mut self) -> Checkpoint {
+        let cp = Checkpoint {
+            height: self.stack.next,
+            log: self.log.next,
+            guard: self.guard,
+            base: self.base,
+            depth: self.depth,
+        };
+        self.base = self.log.next;
+        if self.guard < self.stack.next {
+            self.guard = self.stack.next;
+        }
+        self.depth += 1;
+        cp
+    }
+
+    /// Restore the state remembered by the checkpoint and close it.
+    ///
+    /// # Panics
+    ///
+    /// If the checkpoint is not the last one open, or was made by
+    /// another journal, it will panic.
+    #[inline]
+    pub fn rollback(&mut self, cp: Checkpoint) {
+        self.close(cp);
+        while self.log.next > cp.log {
+            let (pos, v) = self.log.pop();
+            self.stack.items[pos] = v;
+        }
+        self.stack.next = cp.height;
+    }
+
+    /// Keep all changes made since the checkpoint and close it.
+    ///
+    /// # Panics
+    ///
+    /// If the checkpoint is not the last one open, or was made by
+    /// another journal, it will panic.
+    #[inline]
+    pub fn commit(&mut self, cp: Checkpoint) {
+        self.close(cp);
+        if self.depth == 0 {
+            self.log.clear();
+        }
+    }
+
+    /// Close the checkpoint.
+    #[inline]
+    fn close(&mut self, cp: Checkpoint) {
+        assert_eq!(
+            cp.depth + 1,
+            self.depth,
+            "The checkpoint is not the last one open"
+        );
+        assert!(
+            cp.height <= N && cp.guard <= N && cp.base <= cp.log && cp.log <= self.log.next,
+            "The checkpoint doesn't belong to this journal"
+        );
+        self.depth = cp.depth;
+        self.guard = cp.guard;
+        self.base = cp.base;
+    }
+}
+
+#[cfg(test)]
+fn snapshot<const N: usize, const L: usize>(j: &Journal<u8, N, L>) -> Vec<u8> {
+    j.iter().copied().collect()
+}
+
+#[test]
+fn rolls_back_pushes() {
+    let mut j: Journal<u64, 8, 0> = Journal::new();
+    j.push(1);
+    let cp = j.checkpoint();
+    j.push(2);
+    j.push(3);
+    j.rollback(cp);
+    assert_eq!(1, j.len());
+    assert_eq!(1, j.pop());
+}
+
+#[test]
+fn rolls_back_pops() {
+    let mut j: Journal<u64, 8, 0> = Journal::new();
+    j.push(1);
+    j.push(2);
+    let cp = j.checkpoint();
+    j.pop();
+    j.pop();
+    j.rollback(cp);
+    assert_eq!(2, j.pop());
+    assert_eq!(1, j.pop());
+}
+
+#[test]
+fn rolls_back_overwrites() {
+    let mut j: Journal<u64, 8, 2> = Journal::new();
+    j.push(1);
+    j.push(2);
+    let cp = j.checkpoint();
+    j.pop();
+    j.pop();
+    j.push(3);
+    j.push(4);
+    j.push(5);
+    j.rollback(cp);
+    assert_eq!(2, j.pop());
+    assert_eq!(1, j.pop());
+}
+
+#[test]
+fn rolls_back_clear() {
+    let mut j: Journal<u64, 8, 2> = Journal::new();
+    j.push(1);
+    let cp = j.checkpoint();
+    j.clear();
+    j.push(7);
+    j.rollback(cp);
+    assert_eq!(1, j.pop());
+}
+
+#[test]
+fn commits_changes() {
+    let mut j: Journal<u64, 8, 2> = Journal::new();
+    j.push(1);
+    let cp = j.checkpoint();
+    j.pop();
+    j.push(2);
+    j.commit(cp);
+    assert_eq!(0, j.log.len());
+    assert_eq!(2, j.pop());
+}
+
+#[test]
+fn rolls_back_nested_checkpoints() {
+    let mut j: Journal<u64, 8, 8> = Journal::new();
+    j.push(1);
+    j.push(2);
+    let outer = j.checkpoint();
+    j.pop();
+    let inner = j.checkpoint();
+    j.pop();
+    j.push(3);
+    j.push(4);
+    j.rollback(inner);
+    assert_eq!(1, j.len());
+    j.push(5);
+    j.rollback(outer);
+    assert_eq!(2, j.pop());
+    assert_eq!(1, j.pop());
+}
+
+#[test]
+fn keeps_log_when_inner_checkpoint_committed() {
+    let mut j: Journal<u64, 8, 8> = Journal::new();
+    j.push(1);
+    let outer = j.checkpoint();
+    let inner = j.checkpoint();
+    j.pop();
+    j.push(2);
+    j.commit(inner);
+    assert_eq!(1, j.log.len());
+    j.rollback(outer);
+    assert_eq!(1, j.pop());
+}
+
+#[test]
+#[should_panic(expected = "The checkpoint is not the last one open")]
+fn panics_on_closing_out_of_order() {
+    let mut j: Journal<u64, 8, 8> = Journal::new();
+    let outer = j.checkpoint();
+    let _inner = j.checkpoint();
+    j.rollback(outer);
+}
+
+#[test]
+fn fails_when_log_is_full() {
+    let mut j: Journal<u64, 8, 1> = Journal::new();
+    j.push(1);
+    j.push(2);
+    let cp = j.checkpoint();
+    j.pop();
+    j.pop();
+    assert!(j.try_push(3).is_ok());
+    assert!(j.try_push(4).err().unwrap().contains("log"));
+    j.rollback(cp);
+    assert_eq!(2, j.pop());
+}
+
+#[test]
+#[should_panic(expected = "No more space left in the log")]
+fn panics_when_log_is_full() {
+    let mut j: Journal<u64, 8, 0> = Journal::new();
+    j.push(1);
+    let _cp = j.checkpoint();
+    j.pop();
+    j.push(2);
+}
+
+#[test]
+fn does_not_log_without_checkpoints() {
+    let mut j: Journal<u64, 2, 0> = Journal::new();
+    j.push(1);
+    j.pop();
+    j.push(2);
+    assert!(j.try_push(3).is_ok());
+    assert!(j.try_push(4).err().unwrap().contains("stack"));
+}
+
+#[test]
+fn rolls_back_every_sequence_of_operations() {
+    let ops = [None, Some(7), Some(8), Some(9)];
+    let depth = 6;
+    let total = ops.len().pow(depth);
+    for prefix in 0..=3 {
+        for code in 0..total {
+            let mut j: Journal<u8, 16, 16> = Journal::new();
+            for i in 0..prefix {
+                j.push(i);
+            }
+            let before = snapshot(&j);
+            let cp = j.checkpoint();
+            let mut model = before.clone();
+            let mut c = code;
+            for _ in 0..depth {
+                match ops[c % ops.len()] {
+                    None => assert_eq!(model.pop(), j.try_pop().ok()),
+                    Some(v) => {
+                        model.push(v);
+                        j.push(v);
+                    }
+                }
+                c /= ops.len();
+            }
+            assert_eq!(model, snapshot(&j));
+            j.rollback(cp);
+            assert_eq!(before, snapshot(&j));
+        }
+    }
+}
+
+#[test]
+fn logs_each_slot_once_per_checkpoint() {
+    let mut j: Journal<u64, 8, 2> = Journal::new();
+    j.push(1);
+    j.push(2);
+    let cp = j.checkpoint();
+    for i in 0..100 {
+        j.pop();
+        j.push(i);
+    }
+    j.pop();
+    j.pop();
+    j.push(3);
+    j.push(4);
+    assert_eq!(2, j.log.len());
+    j.rollback(cp);
+    assert_eq!(2, j.pop());
+    assert_eq!(1, j.pop());
+}
+
+#[test]
+fn logs_slot_again_in_nested_checkpoint() {
+    let mut j: Journal<u64, 8, 2> = Journal::new();
+    j.push(1);
+    let outer = j.checkpoint();
+    j.pop();
+    j.push(2);
+    let inner = j.checkpoint();
+    j.pop();
+    j.push(3);
+    assert_eq!(2, j.log.len());
+    j.rollback(inner);
+    assert_eq!(2, j.pop());
+    j.push(4);
+    assert_eq!(1, j.log.len());
+    j.rollback(outer);
+    assert_eq!(1, j.pop());
+}
+
+#[test]
+#[should_panic(expected = "The checkpoint doesn't belong to this journal")]
+fn panics_on_checkpoint_of_larger_journal() {
+    let mut big: Journal<u64, 16, 0> = Journal::new();
+    for i in 0..16 {
+        big.push(i);
+    }
+    let cp = big.checkpoint();
+    let mut small: Journal<u64, 4, 0> = Journal::new();
+    let _ = small.checkpoint();
+    small.rollback(cp);
+}
+
+#[test]
+#[should_panic(expected = "The checkpoint doesn't belong to this journal")]
+fn panics_on_stale_checkpoint() {
+    let mut j: Journal<u64, 8, 4> = Journal::new();
+    j.push(1);
+    let outer = j.checkpoint();
+    j.pop();
+    j.push(2);
+    let stale = j.checkpoint();
+    j.rollback(stale);
+    j.rollback(outer);
+    let _ = j.checkpoint();
+    let _ = j.checkpoint();
+    j.rollback(stale);
+}
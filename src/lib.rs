@@ -36,6 +36,9 @@
 //! what the second type argument `10` is for, in the example above. The stack
 //! will have exactly ten elements. An attempt to add an 11th element will lead
 //! to a panic.
+//!
+//! A [`Stack`] is printed bottom first, both by `Debug` and `Display`,
+//! while the alternate `{:#}` form of `Display` prints it top first.
 
 #![doc(html_root_url = "https://docs.rs/microstack/0.0.0")]
 #![deny(rust_2018_idioms, unused, deprecated)]
@@ -45,13 +48,83 @@
 
 use std::marker::PhantomData;
 
+/// Make a [`Stack`] from a list of elements, the last one on top.
+///
+/// The capacity is taken from the type annotation:
+///
+/// ```
+/// use microstack::{stack, Stack};
+/// let mut s : Stack<u64, 10> = stack![1, 2, 3];
+/// assert_eq!(3, s.pop());
+/// assert_eq!(2, s.len());
+/// let z : Stack<u64, 10> = stack![0; 5];
+/// assert_eq!(5, z.len());
+/// ```
+///
+/// It works in `const` contexts too, though the empty form there
+/// needs elements, for which zero bytes are a valid value,
+/// see [`Stack::from_array`]:
+///
+/// ```
+/// use microstack::{stack, Stack};
+/// const PRIMES : Stack<u8, 8> = stack![2, 3, 5, 7];
+/// assert_eq!(4, PRIMES.len());
+/// const ZEROS : Stack<u8, 8> = stack![0; 5];
+/// assert_eq!(5, ZEROS.len());
+/// const EMPTY : Stack<u8, 8> = stack![];
+/// assert!(EMPTY.is_empty());
+/// ```
+///
+/// More elements than the capacity is a compile error:
+///
+/// ```compile_fail
+/// use microstack::{stack, Stack};
+/// let s : Stack<u64, 2> = stack![1, 2, 3];
+/// ```
+#[macro_export]
+macro_rules! stack {
+    () => {
+        $crate::Stack::from_array([])
+    };
+    ($v:expr; $n:expr) => {
+        $crate::Stack::from_array([$v; $n])
+    };
+    ($($v:expr),+ $(,)?) => {
+        $crate::Stack::from_array([$($v),+])
+    };
+}
+
+mod aggregate;
+pub mod algorithms;
 mod clone;
 mod ctors;
 mod debug;
+mod deque;
+mod dual;
+#[cfg(any(feature = "defmt", feature = "ufmt"))]
+mod format;
+mod frame;
+mod generation;
+mod heap;
+mod io;
 mod iterators;
+mod journal;
+mod map;
+mod metered;
+mod minmax;
+mod monotonic;
+#[cfg(feature = "rayon")]
+mod parallel;
+mod queue;
 #[cfg(feature = "serde")]
 mod serialization;
+mod set;
+mod split;
 mod stack;
+#[cfg(feature = "futures")]
+mod stream;
+mod string;
+mod transform;
 
 /// This is a simplest and the fastest implementation of a stack on stack,
 /// when stack elements are `Copy` implementing primitives.
@@ -84,6 +157,34 @@ pub struct Iter<'a, V: Copy, const N: usize> {
     _marker: PhantomData<&'a V>,
 }
 
+/// Iterator, made by [`Stack::pop_while`], which pops elements
+/// from a [`Stack`] while they satisfy the predicate.
+///
+/// Elements are popped lazily, as the iterator advances.
+pub struct PopWhile<'a, V: Copy, const N: usize, F: FnMut(&V) -> bool> {
+    /// The stack.
+    stack: &'a mut Stack<V, N>,
+    /// The predicate.
+    pred: F,
+}
+
+/// A view of a [`Stack`], made by [`Stack::detailed`], which is
+/// `Debug`-formatted as a struct, showing its length and capacity.
+///
+/// ```
+/// use microstack::Stack;
+/// let mut s : Stack<u64, 4> = Stack::new();
+/// s.push(42);
+/// assert_eq!(
+///     "Stack { len: 1, capacity: 4, items: [42] }",
+///     format!("{:?}", s.detailed()),
+/// );
+/// ```
+pub struct Detailed<'a, V: Copy, const N: usize> {
+    /// The stack.
+    stack: &'a Stack<V, N>,
+}
+
 /// Into-iterator.
 pub struct IntoIter<V: Copy, const N: usize> {
     /// The position.
@@ -93,3 +194,415 @@ pub struct IntoIter<V: Copy, const N: usize> {
     /// The fixed-size array of values.
     items: [V; N],
 }
+
+/// A saved height of a [`Stack`], made by [`Stack::mark`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Mark {
+    /// The height of the stack at the moment of marking.
+    height: usize,
+}
+
+/// Frame, made by [`Stack::enter`], which unwinds the stack back
+/// to its original height when dropped.
+pub struct Frame<'a, V: Copy, const N: usize> {
+    /// The stack.
+    stack: &'a mut Stack<V, N>,
+    /// The height to unwind to.
+    mark: Mark,
+}
+
+/// A [`Stack`] that can roll back to a [`Checkpoint`].
+///
+/// Every element overwritten below the height of an open checkpoint
+/// is saved into a log of `L` entries, which is also allocated on stack.
+/// Each slot is saved at most once per checkpoint.
+///
+/// ```
+/// use microstack::Journal;
+/// let mut j : Journal<u64, 10, 4> = Journal::new();
+/// j.push(1);
+/// let cp = j.checkpoint();
+/// j.pop();
+/// j.push(2);
+/// j.rollback(cp);
+/// assert_eq!(1, j.pop());
+/// ```
+pub struct Journal<V: Copy, const N: usize, const L: usize> {
+    /// The stack.
+    stack: Stack<V, N>,
+    /// The positions and values overwritten since the first checkpoint.
+    log: Stack<(usize, V), L>,
+    /// The height, below which overwrites must be logged.
+    guard: usize,
+    /// The length of the log at the last open checkpoint.
+    base: usize,
+    /// The number of open checkpoints.
+    depth: usize,
+}
+
+/// A state of a [`Journal`], made by [`Journal::checkpoint`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Checkpoint {
+    /// The height of the stack.
+    height: usize,
+    /// The length of the log.
+    log: usize,
+    /// The guard of the journal, before the checkpoint.
+    guard: usize,
+    /// The base of the journal, before the checkpoint.
+    base: usize,
+    /// The number of checkpoints open before this one.
+    depth: usize,
+}
+
+/// A [`Stack`] that knows its smallest and largest elements.
+///
+/// ```
+/// use microstack::MinMaxStack;
+/// let mut s : MinMaxStack<u64, 10> = MinMaxStack::new();
+/// s.push(3);
+/// s.push(1);
+/// s.push(2);
+/// assert_eq!(1, s.min());
+/// assert_eq!(3, s.max());
+/// ```
+pub struct MinMaxStack<V: Ord + Copy, const N: usize> {
+    /// The elements.
+    stack: Stack<V, N>,
+    /// The non-increasing sequence of minimums.
+    mins: Stack<V, N>,
+    /// The non-decreasing sequence of maximums.
+    maxs: Stack<V, N>,
+}
+
+/// A [`Stack`] that knows the result of folding all its elements
+/// with an associative operator.
+///
+/// ```
+/// use microstack::AggregateStack;
+/// let mut s = AggregateStack::<u64, 10, _>::new(|a, b| a + b);
+/// s.push(3);
+/// s.push(4);
+/// assert_eq!(7, s.fold());
+/// ```
+pub struct AggregateStack<V: Copy, const N: usize, F: Fn(V, V) -> V> {
+    /// The elements, each one with the fold of all elements below it.
+    stack: Stack<(V, V), N>,
+    /// The operator.
+    op: F,
+}
+
+/// An order of elements in a [`MonotonicStack`], from bottom to top.
+pub trait Order<V> {
+    /// Can `above` be placed right on top of `below`?
+    fn keeps(below: &V, above: &V) -> bool;
+}
+
+/// Non-decreasing [`Order`], from bottom to top.
+pub struct Increasing;
+
+/// Strictly increasing [`Order`], from bottom to top.
+pub struct StrictlyIncreasing;
+
+/// Non-increasing [`Order`], from bottom to top.
+pub struct Decreasing;
+
+/// Strictly decreasing [`Order`], from bottom to top.
+pub struct StrictlyDecreasing;
+
+/// A [`Stack`] that keeps its elements in the [`Order`], evicting
+/// all elements that violate it, when a new one is pushed.
+///
+/// ```
+/// use microstack::{Increasing, MonotonicStack};
+/// let mut s : MonotonicStack<u64, 10, Increasing> = MonotonicStack::new();
+/// s.push(1);
+/// s.push(5);
+/// s.push(3);
+/// let evicted: Vec<u64> = s.push(2).collect();
+/// assert_eq!(vec![3], evicted);
+/// assert_eq!(2, s.len());
+/// ```
+pub struct MonotonicStack<V: Copy, const N: usize, O: Order<V>> {
+    /// The elements.
+    stack: Stack<V, N>,
+    _marker: PhantomData<O>,
+}
+
+/// Iterator over elements evicted from a [`MonotonicStack`] by a push,
+/// from top to bottom.
+///
+/// The new element is pushed when the iterator is exhausted or dropped.
+pub struct Evicted<'a, V: Copy, const N: usize, O: Order<V>> {
+    /// The stack.
+    stack: &'a mut MonotonicStack<V, N, O>,
+    /// The element to push.
+    v: V,
+    /// Is it already pushed?
+    pushed: bool,
+    /// Must the space be checked again right before the push?
+    checked: bool,
+}
+
+/// Two stacks sharing one fixed-size array, the [`Left`] one growing
+/// from its beginning and the [`Right`] one growing from its end.
+///
+/// ```
+/// use microstack::DualStack;
+/// let mut s : DualStack<u64, 4> = DualStack::new();
+/// s.left().push(1);
+/// s.right().push(2);
+/// s.right().push(3);
+/// assert_eq!(3, s.right().pop());
+/// assert_eq!(2, s.len());
+/// ```
+pub struct DualStack<V: Copy, const N: usize> {
+    /// The number of elements in the left stack.
+    left: usize,
+    /// The number of elements in the right stack.
+    right: usize,
+    /// The fixed-size array of values.
+    items: [V; N],
+}
+
+/// One of the two stacks of a [`DualStack`].
+pub struct Side<'a, V: Copy, const N: usize, const LEFT: bool> {
+    /// The dual stack.
+    stack: &'a mut DualStack<V, N>,
+}
+
+/// The left stack of a [`DualStack`], made by [`DualStack::left`].
+pub type Left<'a, V, const N: usize> = Side<'a, V, N, true>;
+
+/// The right stack of a [`DualStack`], made by [`DualStack::right`].
+pub type Right<'a, V, const N: usize> = Side<'a, V, N, false>;
+
+/// Iterator over one [`Side`] of a [`DualStack`], from bottom to top.
+pub struct SideIter<'a, V: Copy, const LEFT: bool> {
+    /// The elements.
+    items: std::slice::Iter<'a, V>,
+}
+
+/// A double-ended queue on stack, which is a ring buffer
+/// over a fixed-size array.
+///
+/// ```
+/// use microstack::Deque;
+/// let mut d : Deque<u64, 10> = Deque::new();
+/// d.push_back(1);
+/// d.push_front(2);
+/// assert_eq!(1, d.pop_back());
+/// assert_eq!(2, d.pop_front());
+/// ```
+pub struct Deque<V: Copy, const N: usize> {
+    /// The position of the first element in the array.
+    head: usize,
+    /// The number of elements.
+    len: usize,
+    /// The fixed-size array of values.
+    items: [V; N],
+}
+
+/// A first-in-first-out queue on stack.
+///
+/// ```
+/// use microstack::Queue;
+/// let mut q : Queue<u64, 10> = Queue::new();
+/// q.push(1);
+/// q.push(2);
+/// assert_eq!(1, q.pop());
+/// ```
+pub struct Queue<V: Copy, const N: usize> {
+    /// The ring buffer.
+    deque: Deque<V, N>,
+}
+
+/// Iterator over a [`Deque`] or a [`Queue`], from front to back.
+pub struct DequeIter<'a, V: Copy, const N: usize> {
+    /// The deque.
+    deque: &'a Deque<V, N>,
+    /// The position of the next element to return, from the front.
+    pos: usize,
+    /// The position after the last element to return, from the front.
+    end: usize,
+}
+
+/// Into-iterator over a [`Deque`] or a [`Queue`], from front to back.
+pub struct DequeIntoIter<V: Copy, const N: usize> {
+    /// The deque.
+    deque: Deque<V, N>,
+}
+
+/// A binary max-heap on stack, which always pops its largest element.
+///
+/// For a min-heap, wrap elements into [`std::cmp::Reverse`].
+///
+/// ```
+/// use microstack::Heap;
+/// let mut h : Heap<u64, 10> = Heap::new();
+/// h.push(2);
+/// h.push(7);
+/// h.push(5);
+/// assert_eq!(7, h.pop());
+/// assert_eq!(5, h.pop());
+/// ```
+pub struct Heap<V: Ord + Copy, const N: usize> {
+    /// The elements, as a complete binary tree.
+    stack: Stack<V, N>,
+}
+
+/// A set on stack, which keeps its elements sorted.
+///
+/// ```
+/// use microstack::StackSet;
+/// let mut s : StackSet<u64, 10> = StackSet::new();
+/// s.insert(5);
+/// s.insert(1);
+/// s.insert(5);
+/// assert!(s.contains(&1));
+/// assert_eq!(2, s.len());
+/// ```
+pub struct StackSet<V: Ord + Copy, const N: usize> {
+    /// The elements, in ascending order.
+    stack: Stack<V, N>,
+}
+
+/// A map on stack, which keeps its entries sorted by keys.
+///
+/// ```
+/// use microstack::StackMap;
+/// let mut m : StackMap<u64, &str, 10> = StackMap::new();
+/// m.insert(2, "two");
+/// m.insert(1, "one");
+/// assert_eq!(Some(&"two"), m.get(&2));
+/// assert_eq!(2, m.len());
+/// ```
+pub struct StackMap<K: Ord + Copy, V: Copy, const N: usize> {
+    /// The entries, in ascending order of keys.
+    stack: Stack<(K, V), N>,
+}
+
+/// Iterator over entries of a [`StackMap`], in ascending order of keys.
+pub struct MapIter<'a, K, V> {
+    /// The entries.
+    items: std::slice::Iter<'a, (K, V)>,
+}
+
+/// A UTF-8 string on stack, of at most `N` bytes.
+///
+/// ```
+/// use microstack::StackString;
+/// let mut s : StackString<16> = StackString::new();
+/// s.push_str("Hello");
+/// s.push('!');
+/// assert_eq!("Hello!", s.as_str());
+/// assert_eq!(Some('!'), s.pop());
+/// ```
+pub struct StackString<const N: usize> {
+    /// The bytes, always a valid UTF-8.
+    stack: Stack<u8, N>,
+}
+
+/// Reader of bytes from a [`Stack`], made by [`Stack::reader`], which
+/// consumes them from the bottom.
+///
+/// When dropped, it removes all bytes read from the stack.
+#[cfg(feature = "std")]
+pub struct Reader<'a, const N: usize> {
+    /// The stack.
+    stack: &'a mut Stack<u8, N>,
+    /// The number of bytes already read.
+    pos: usize,
+}
+
+/// A [`Stack`] that counts operations on it and remembers how high
+/// it ever was, which helps choosing the right capacity.
+///
+/// ```
+/// use microstack::Metered;
+/// let mut s : Metered<u64, 10> = Metered::new();
+/// s.push(1);
+/// s.push(2);
+/// s.pop();
+/// assert_eq!(2, s.high_water_mark());
+/// assert_eq!(2, s.stats().pushes);
+/// ```
+pub struct Metered<V: Copy, const N: usize> {
+    /// The stack.
+    stack: Stack<V, N>,
+    /// The statistics.
+    stats: Stats,
+}
+
+/// Statistics of a [`Metered`] stack.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Stats {
+    /// The number of elements pushed.
+    pub pushes: usize,
+    /// The number of elements popped.
+    pub pops: usize,
+    /// The number of attempts to push into a full stack.
+    pub overflows: usize,
+    /// The largest length the stack ever had.
+    pub high_water_mark: usize,
+}
+
+/// The order in which [`Stack::into_stream`] produces elements.
+#[cfg(feature = "futures")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Direction {
+    /// Last in, first out: from the top to the bottom.
+    Lifo,
+    /// First in, first out: from the bottom to the top.
+    Fifo,
+}
+
+/// A stream, made by [`Stack::into_stream`], which drains a [`Stack`].
+#[cfg(feature = "futures")]
+pub struct IntoStream<V: Copy, const N: usize> {
+    /// The stack.
+    stack: Stack<V, N>,
+    /// The position of the next element, in the FIFO order.
+    pos: usize,
+    /// The order.
+    direction: Direction,
+}
+
+/// An error of [`StackExt::collect_stream`], when the stream has
+/// more elements than the capacity of the stack.
+///
+/// Nothing is lost: it holds the full stack and the first element
+/// that didn't fit, while the rest stay in the stream.
+#[cfg(feature = "futures")]
+#[derive(Debug)]
+pub struct Overflow<V: Copy, const N: usize> {
+    /// The full stack.
+    pub stack: Stack<V, N>,
+    /// The element that didn't fit.
+    pub item: V,
+}
+
+/// An extension of streams, which can be collected into a [`Stack`].
+///
+/// ```
+/// use futures::executor::block_on;
+/// use futures::stream;
+/// use microstack::{Stack, StackExt};
+/// let s : Stack<u8, 4> = block_on(stream::iter([1, 2]).collect_stream()).unwrap();
+/// assert_eq!(2, s.len());
+/// ```
+#[cfg(feature = "futures")]
+pub trait StackExt: futures::Stream + Sized
+where
+    Self::Item: Copy,
+{
+    /// Collect all elements of the stream into a [`Stack`], the first
+    /// one at the bottom.
+    ///
+    /// The future resolves to [`Overflow`] as soon as the stream has
+    /// more elements than the stack can hold. Pass the stream by mutable
+    /// reference to keep the rest of it.
+    fn collect_stream<const N: usize>(
+        self,
+    ) -> impl std::future::Future<Output = Result<Stack<Self::Item, N>, Overflow<Self::Item, N>>>;
+}
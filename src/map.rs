@@ -0,0 +1,319 @@
+// Copyright (c) 2023 Yegor Bugayenko
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::set::span;
+#[cfg(test)]
+use crate::set::{Fickle, CALLS, LIES};
+use crate::{MapIter, Stack, StackMap};
+use std::ops::RangeBounds;
+
+impl<K: Ord + Copy, V: Copy, const N: usize> Default for StackMap<K, V, N> {
+    /// Make a default empty [`StackMap`].
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Ord + Copy, V: Copy, const N: usize> StackMap<K, V, N> {
+    /// Make it.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            stack: Stack::new(),
+        }
+    }
+
+    /// All entries, in ascending order of keys.
+    #[inline]
+    fn items(&self) -> &[(K, V)] {
+        &self.stack.items[..self.stack.next]
+    }
+
+    /// Find the position of the key.
+    #[inline]
+    fn find(&self, k: &K) -> Result<usize, usize> {
+        self.items().binary_search_by(|(x, _)| x.cmp(k))
+    }
+
+    /// Insert new entry, replacing the value if the key is already there.
+    ///
+    /// Returns the previous value, if any.
+    ///
+    /// # Safety
+    ///
+    /// It may lead to undefined behavior, if you go over the boundary.
+    #[inline]
+    pub unsafe fn insert_unchecked(&mut self, k: K, v: V) -> Option<V> {
+        match self.find(&k) {
+            Ok(i) => Some(std::mem::replace(&mut self.stack.items[i].1, v)),
+            Err(i) => {
+                self.stack.insert_unchecked(i, (k, v));
+                None
+            }
+        }
+    }
+
+    /// Insert new entry, replacing the value if the key is already there.
+    ///
+    /// Returns the previous value, if any.
+    ///
+    /// # Panics
+    ///
+    /// If the key is not there and there is no more space
+    /// in the map, it will panic.
+    #[inline]
+    pub fn insert(&mut self, k: K, v: V) -> Option<V> {
+        match self.find(&k) {
+            Ok(i) => Some(std::mem::replace(&mut self.stack.items[i].1, v)),
+            Err(i) => {
+                assert!(self.stack.next < N, "No more space left in the map");
+                unsafe { self.stack.insert_unchecked(i, (k, v)) };
+                None
+            }
+        }
+    }
+
+    /// Makes an attempt to insert new entry, replacing the value
+    /// if the key is already there.
+    ///
+    /// Returns the previous value, if any.
+    ///
+    /// # Errors
+    ///
+    /// If the key is not there and there is not enough space
+    /// in the map, `Err` is returned.
+    #[inline]
+    pub fn try_insert(&mut self, k: K, v: V) -> Result<Option<V>, String> {
+        match self.find(&k) {
+            Ok(i) => Ok(Some(std::mem::replace(&mut self.stack.items[i].1, v))),
+            Err(i) => {
+                if self.stack.next < N {
+                    unsafe { self.stack.insert_unchecked(i, (k, v)) };
+                    Ok(None)
+                } else {
+                    Err(format!("There are no space left in the map of {N}"))
+                }
+            }
+        }
+    }
+
+    /// Remove the entry, returning its value, if it was there.
+    #[inline]
+    pub fn remove(&mut self, k: &K) -> Option<V> {
+        self.find(k)
+            .ok()
+            .map(|i| unsafe { self.stack.remove_unchecked(i) }.1)
+    }
+
+    /// Is the key there.
+    #[inline]
+    #[must_use]
+    pub fn contains_key(&self, k: &K) -> bool {
+        self.find(k).is_ok()
+    }
+
+    /// Get the value by the key, if any.
+    #[inline]
+    #[must_use]
+    pub fn get(&self, k: &K) -> Option<&V> {
+        self.find(k).ok().map(|i| &self.stack.items[i].1)
+    }
+
+    /// Get the mutable value by the key, if any.
+    #[inline]
+    #[must_use]
+    pub fn get_mut(&mut self, k: &K) -> Option<&mut V> {
+        self.find(k).ok().map(|i| &mut self.stack.items[i].1)
+    }
+
+    /// Iterate entries with keys in the range, in ascending order of keys.
+    #[inline]
+    pub fn range<R: RangeBounds<K>>(&self, range: R) -> MapIter<'_, K, V> {
+        let items = self.items();
+        let (lo, hi) = span(items, |(k, _)| k, &range);
+        MapIter {
+            items: items[lo..hi].iter(),
+        }
+    }
+
+    /// Iterate entries, in ascending order of keys.
+    #[inline]
+    #[must_use]
+    pub fn iter(&self) -> MapIter<'_, K, V> {
+        MapIter {
+            items: self.items().iter(),
+        }
+    }
+
+    /// Clear.
+    #[inline]
+    pub const fn clear(&mut self) {
+        self.stack.clear();
+    }
+
+    /// Is it empty.
+    #[inline]
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.stack.is_empty()
+    }
+
+    /// Length of it.
+    #[inline]
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.stack.len()
+    }
+}
+
+impl<'a, K, V> Iterator for MapIter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.items.next().map(|(k, v)| (k, v))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.items.size_hint()
+    }
+}
+
+impl<K, V> DoubleEndedIterator for MapIter<'_, K, V> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.items.next_back().map(|(k, v)| (k, v))
+    }
+}
+
+impl<K, V> ExactSizeIterator for MapIter<'_, K, V> {}
+
+impl<'a, K: Ord + Copy, V: Copy, const N: usize> IntoIterator for &'a StackMap<K, V, N> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = MapIter<'a, K, V>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[test]
+fn keeps_entries_sorted() {
+    let mut m: StackMap<u64, char, 8> = StackMap::new();
+    m.insert(3, 'c');
+    m.insert(1, 'a');
+    m.insert(2, 'b');
+    assert_eq!(
+        vec![(&1, &'a'), (&2, &'b'), (&3, &'c')],
+        m.iter().collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn replaces_values() {
+    let mut m: StackMap<u64, char, 8> = StackMap::new();
+    assert_eq!(None, m.insert(1, 'a'));
+    assert_eq!(Some('a'), m.insert(1, 'b'));
+    assert_eq!(Some(&'b'), m.get(&1));
+    *m.get_mut(&1).unwrap() = 'c';
+    assert_eq!(Some(&'c'), m.get(&1));
+    assert_eq!(1, m.len());
+}
+
+#[test]
+fn removes_entries() {
+    let mut m: StackMap<u64, char, 8> = StackMap::new();
+    m.insert(1, 'a');
+    m.insert(2, 'b');
+    assert_eq!(Some('a'), m.remove(&1));
+    assert_eq!(None, m.remove(&1));
+    assert!(!m.contains_key(&1));
+    assert!(m.contains_key(&2));
+}
+
+#[test]
+fn queries_ranges() {
+    let mut m: StackMap<u64, u64, 16> = StackMap::new();
+    for k in 0..10 {
+        m.insert(k, k * k);
+    }
+    assert_eq!(
+        vec![16, 25],
+        m.range(4..6).map(|(_, v)| *v).collect::<Vec<_>>()
+    );
+    assert_eq!(Some((&9, &81)), m.range(8..).next_back());
+}
+
+#[test]
+fn fails_when_full() {
+    let mut m: StackMap<u64, char, 1> = StackMap::new();
+    assert_eq!(Ok(None), m.try_insert(1, 'a'));
+    assert_eq!(Ok(Some('a')), m.try_insert(1, 'b'));
+    assert!(m.try_insert(2, 'c').err().unwrap().contains("space"));
+}
+
+#[test]
+#[should_panic(expected = "No more space left in the map")]
+fn panics_when_full() {
+    let mut m: StackMap<u64, char, 0> = StackMap::new();
+    m.insert(1, 'a');
+}
+
+#[cfg(test)]
+use proptest::prelude::*;
+
+#[cfg(test)]
+proptest! {
+    #[test]
+    fn matches_btree_map(
+        ops in proptest::collection::vec((0..48u8, proptest::option::of(any::<u8>())), 0..256)
+    ) {
+        let mut m: StackMap<u8, u8, 32> = StackMap::new();
+        let mut model = std::collections::BTreeMap::new();
+        for (k, op) in ops {
+            if let Some(v) = op {
+                match m.try_insert(k, v) {
+                    Ok(old) => prop_assert_eq!(model.insert(k, v), old),
+                    Err(_) => prop_assert!(model.len() == 32 && !model.contains_key(&k)),
+                }
+            } else {
+                prop_assert_eq!(model.remove(&k), m.remove(&k));
+            }
+            prop_assert!(model.iter().eq(m.iter()));
+        }
+    }
+}
+
+#[test]
+fn never_overflows_with_broken_order() {
+    let mut m: StackMap<Fickle, u8, 2> = StackMap::new();
+    m.insert(Fickle(1), 1);
+    m.insert(Fickle(2), 2);
+    CALLS.with(|c| c.set(0));
+    let _ = m.contains_key(&Fickle(3));
+    LIES.with(|l| l.set(Some(CALLS.with(std::cell::Cell::get))));
+    let _ = m.try_insert(Fickle(3), 3);
+    LIES.with(|l| l.set(None));
+    assert_eq!(2, m.len());
+}
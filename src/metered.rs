@@ -0,0 +1,243 @@
+// Copyright (c) 2023 Yegor Bugayenko
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{Metered, Stack, Stats};
+use std::ops::Deref;
+
+impl<V: Copy, const N: usize> Default for Metered<V, N> {
+    /// Make a default empty [`Metered`].
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<V: Copy, const N: usize> From<Stack<V, N>> for Metered<V, N> {
+    /// Wrap an existing [`Stack`], counting its elements as
+    /// the high water mark.
+    #[inline]
+    fn from(stack: Stack<V, N>) -> Self {
+        Self {
+            stats: Stats {
+                high_water_mark: stack.next,
+                ..Stats::default()
+            },
+            stack,
+        }
+    }
+}
+
+impl<V: Copy, const N: usize> Deref for Metered<V, N> {
+    type Target = Stack<V, N>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.stack
+    }
+}
+
+impl<V: Copy, const N: usize> Metered<V, N> {
+    /// Make it.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            stack: Stack::new(),
+            stats: Stats {
+                pushes: 0,
+                pops: 0,
+                overflows: 0,
+                high_water_mark: 0,
+            },
+        }
+    }
+
+    /// Take the stack out of it, forgetting the statistics.
+    #[inline]
+    #[must_use]
+    pub const fn into_inner(self) -> Stack<V, N> {
+        self.stack
+    }
+
+    /// Get the statistics.
+    #[inline]
+    #[must_use]
+    pub const fn stats(&self) -> Stats {
+        self.stats
+    }
+
+    /// Get the largest length the stack ever had.
+    #[inline]
+    #[must_use]
+    pub const fn high_water_mark(&self) -> usize {
+        self.stats.high_water_mark
+    }
+
+    /// Forget the statistics, starting from the current length.
+    #[inline]
+    pub const fn reset_stats(&mut self) {
+        self.stats = Stats {
+            pushes: 0,
+            pops: 0,
+            overflows: 0,
+            high_water_mark: self.stack.next,
+        };
+    }
+
+    /// Push new element into it.
+    ///
+    /// # Safety
+    ///
+    /// It may lead to undefined behavior, if you go over the boundary.
+    #[inline]
+    pub const unsafe fn push_unchecked(&mut self, v: V) {
+        self.stack.push_unchecked(v);
+        self.stats.pushes += 1;
+        if self.stack.next > self.stats.high_water_mark {
+            self.stats.high_water_mark = self.stack.next;
+        }
+    }
+
+    /// Push new element into it.
+    ///
+    /// # Panics
+    ///
+    /// If there is no more space in the stack, it will panic.
+    #[inline]
+    pub fn push(&mut self, v: V) {
+        if self.stack.next >= N {
+            self.stats.overflows += 1;
+        }
+        assert!(self.stack.next < N, "No more space left in the stack");
+        unsafe {
+            self.push_unchecked(v);
+        }
+    }
+
+    /// Makes an attempt to push a new element into the stack.
+    ///
+    /// # Errors
+    ///
+    /// If there is not enough space in the stack, `Err` is returned.
+    #[inline]
+    pub fn try_push(&mut self, v: V) -> Result<(), String> {
+        if self.stack.next < N {
+            self.push(v);
+            Ok(())
+        } else {
+            self.stats.overflows += 1;
+            Err(format!("There are no space left in the stack of {N}"))
+        }
+    }
+
+    /// Pop a element from it.
+    ///
+    /// # Safety
+    ///
+    /// If there are no items in the array, the result is undefined.
+    #[inline]
+    pub const unsafe fn pop_unchecked(&mut self) -> V {
+        self.stats.pops += 1;
+        self.stack.pop_unchecked()
+    }
+
+    /// Pop a element from it.
+    ///
+    /// # Panics
+    ///
+    /// If there are no items in the array, it will panic.
+    #[inline]
+    pub fn pop(&mut self) -> V {
+        assert!(self.stack.next > 0, "No more items left in the stack");
+        unsafe { self.pop_unchecked() }
+    }
+
+    /// Pop a element from it.
+    ///
+    /// # Errors
+    ///
+    /// If there is no more elements left, it will return `Err`.
+    #[inline]
+    pub fn try_pop(&mut self) -> Result<V, String> {
+        if self.stack.next == 0 {
+            Err(format!("There are no items left in the stack of {N}"))
+        } else {
+            Ok(self.pop())
+        }
+    }
+
+    /// Clear.
+    #[inline]
+    pub const fn clear(&mut self) {
+        self.stack.clear();
+    }
+}
+
+#[test]
+fn tracks_high_water_mark() {
+    let mut s: Metered<u64, 8> = Metered::new();
+    s.push(1);
+    s.push(2);
+    s.push(3);
+    s.pop();
+    s.pop();
+    s.push(4);
+    assert_eq!(3, s.high_water_mark());
+    assert_eq!(2, s.len());
+}
+
+#[test]
+fn counts_operations() {
+    let mut s: Metered<u64, 1> = Metered::new();
+    s.push(1);
+    assert!(s.try_push(2).is_err());
+    assert!(s.try_push(3).is_err());
+    s.pop();
+    assert!(s.try_pop().is_err());
+    assert_eq!(
+        Stats {
+            pushes: 1,
+            pops: 1,
+            overflows: 2,
+            high_water_mark: 1,
+        },
+        s.stats()
+    );
+}
+
+#[test]
+fn counts_overflow_before_panic() {
+    let mut s: Metered<u64, 0> = Metered::new();
+    let r = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| s.push(1)));
+    assert!(r.is_err());
+    assert_eq!(1, s.stats().overflows);
+}
+
+#[test]
+fn resets_stats() {
+    let mut s: Metered<u64, 4> = Metered::from(Stack::from_vec(vec![1, 2, 3]));
+    assert_eq!(3, s.high_water_mark());
+    s.pop();
+    s.reset_stats();
+    assert_eq!(2, s.high_water_mark());
+    assert_eq!(0, s.stats().pops);
+    s.clear();
+    assert!(s.into_inner().is_empty());
+}
@@ -0,0 +1,390 @@
+// Copyright (c) 2023 Yegor Bugayenko
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{MinMaxStack, Stack};
+use std::ops::Deref;
+
+impl<V: Ord + Copy, const N: usize> Default for MinMaxStack<V, N> {
+    /// Make a default empty [`MinMaxStack`].
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<V: Ord + Copy, const N: usize> Deref for MinMaxStack<V, N> {
+    type Target = Stack<V, N>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.stack
+    }
+}
+
+/// The top element of a stack, which must not be empty.
+#[inline]
+const unsafe fn top<V: Copy, const N: usize>(s: &Stack<V, N>) -> V {
+    s.items.as_ptr().add(s.next - 1).read()
+}
+
+impl<V: Ord + Copy, const N: usize> MinMaxStack<V, N> {
+    /// Make it.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            stack: Stack::new(),
+            mins: Stack::new(),
+            maxs: Stack::new(),
+        }
+    }
+
+    /// Push new element into it.
+    ///
+    /// # Safety
+    ///
+    /// It may lead to undefined behavior, if you go over the boundary.
+    #[inline]
+    pub unsafe fn push_unchecked(&mut self, v: V) {
+        if self.mins.next == 0 || v <= top(&self.mins) {
+            self.mins.push_unchecked(v);
+        }
+        if self.maxs.next == 0 || v >= top(&self.maxs) {
+            self.maxs.push_unchecked(v);
+        }
+        self.stack.push_unchecked(v);
+    }
+
+    /// Push new element into it.
+    ///
+    /// # Panics
+    ///
+    /// If there is no more space in the stack, it will panic.
+    #[inline]
+    pub fn push(&mut self, v: V) {
+        assert!(self.stack.next < N, "No more space left in the stack");
+        unsafe {
+            self.push_unchecked(v);
+        }
+    }
+
+    /// Makes an attempt to push a new element into the stack.
+    ///
+    /// # Errors
+    ///
+    /// If there is not enough space in the stack, `Err` is returned.
+    #[inline]
+    pub fn try_push(&mut self, v: V) -> Result<(), String> {
+        if self.stack.next < N {
+            self.push(v);
+            Ok(())
+        } else {
+            Err(format!("There are no space left in the stack of {N}"))
+        }
+    }
+
+    /// Pop a element from it.
+    ///
+    /// # Safety
+    ///
+    /// If there are no items in the array, the result is undefined.
+    #[inline]
+    pub unsafe fn pop_unchecked(&mut self) -> V {
+        let v = self.stack.pop_unchecked();
+        if self.mins.next > 0 && v == top(&self.mins) {
+            self.mins.pop_unchecked();
+        }
+        if self.maxs.next > 0 && v == top(&self.maxs) {
+            self.maxs.pop_unchecked();
+        }
+        v
+    }
+
+    /// Pop a element from it.
+    ///
+    /// # Panics
+    ///
+    /// If there are no items in the array, it will panic.
+    #[inline]
+    pub fn pop(&mut self) -> V {
+        assert!(self.stack.next > 0, "No more items left in the stack");
+        unsafe { self.pop_unchecked() }
+    }
+
+    /// Pop a element from it.
+    ///
+    /// # Errors
+    ///
+    /// If there is no more elements left, it will return `Err`.
+    #[inline]
+    pub fn try_pop(&mut self) -> Result<V, String> {
+        if self.stack.next == 0 {
+            Err(format!("There are no items left in the stack of {N}"))
+        } else {
+            Ok(self.pop())
+        }
+    }
+
+    /// Get the top element, without popping it.
+    ///
+    /// # Safety
+    ///
+    /// If there are no items in the array, the result is undefined.
+    #[inline]
+    #[must_use]
+    pub const unsafe fn peek_unchecked(&self) -> V {
+        top(&self.stack)
+    }
+
+    /// Get the top element, without popping it.
+    ///
+    /// # Panics
+    ///
+    /// If there are no items in the array, it will panic.
+    #[inline]
+    #[must_use]
+    pub fn peek(&self) -> V {
+        assert!(self.stack.next > 0, "No more items left in the stack");
+        unsafe { self.peek_unchecked() }
+    }
+
+    /// Get the top element, without popping it.
+    ///
+    /// # Errors
+    ///
+    /// If there is no elements, it will return `Err`.
+    #[inline]
+    pub fn try_peek(&self) -> Result<V, String> {
+        if self.stack.next == 0 {
+            Err(format!("There are no items left in the stack of {N}"))
+        } else {
+            Ok(self.peek())
+        }
+    }
+
+    /// Get the smallest element.
+    ///
+    /// # Safety
+    ///
+    /// If there are no items in the array, the result is undefined.
+    #[inline]
+    #[must_use]
+    pub const unsafe fn min_unchecked(&self) -> V {
+        top(&self.mins)
+    }
+
+    /// Get the smallest element.
+    ///
+    /// # Panics
+    ///
+    /// If there are no items in the array, it will panic.
+    #[inline]
+    #[must_use]
+    pub fn min(&self) -> V {
+        assert!(self.mins.next > 0, "No more items left in the stack");
+        unsafe { self.min_unchecked() }
+    }
+
+    /// Get the smallest element.
+    ///
+    /// # Errors
+    ///
+    /// If there is no elements, it will return `Err`.
+    #[inline]
+    pub fn try_min(&self) -> Result<V, String> {
+        if self.mins.next == 0 {
+            Err(format!("There are no items left in the stack of {N}"))
+        } else {
+            Ok(self.min())
+        }
+    }
+
+    /// Get the largest element.
+    ///
+    /// # Safety
+    ///
+    /// If there are no items in the array, the result is undefined.
+    #[inline]
+    #[must_use]
+    pub const unsafe fn max_unchecked(&self) -> V {
+        top(&self.maxs)
+    }
+
+    /// Get the largest element.
+    ///
+    /// # Panics
+    ///
+    /// If there are no items in the array, it will panic.
+    #[inline]
+    #[must_use]
+    pub fn max(&self) -> V {
+        assert!(self.maxs.next > 0, "No more items left in the stack");
+        unsafe { self.max_unchecked() }
+    }
+
+    /// Get the largest element.
+    ///
+    /// # Errors
+    ///
+    /// If there is no elements, it will return `Err`.
+    #[inline]
+    pub fn try_max(&self) -> Result<V, String> {
+        if self.maxs.next == 0 {
+            Err(format!("There are no items left in the stack of {N}"))
+        } else {
+            Ok(self.max())
+        }
+    }
+
+    /// Clear.
+    #[inline]
+    pub const fn clear(&mut self) {
+        self.stack.clear();
+        self.mins.clear();
+        self.maxs.clear();
+    }
+}
+
+#[test]
+fn tracks_min_and_max() {
+    let mut s: MinMaxStack<i32, 8> = MinMaxStack::new();
+    s.push(5);
+    assert_eq!((5, 5), (s.min(), s.max()));
+    s.push(2);
+    s.push(9);
+    assert_eq!((2, 9), (s.min(), s.max()));
+    assert_eq!(9, s.pop());
+    assert_eq!((2, 5), (s.min(), s.max()));
+    assert_eq!(2, s.pop());
+    assert_eq!((5, 5), (s.min(), s.max()));
+}
+
+#[test]
+fn tracks_duplicates() {
+    let mut s: MinMaxStack<i32, 8> = MinMaxStack::new();
+    s.push(1);
+    s.push(1);
+    s.push(3);
+    s.push(3);
+    s.pop();
+    assert_eq!((1, 3), (s.min(), s.max()));
+    s.pop();
+    s.pop();
+    assert_eq!((1, 1), (s.min(), s.max()));
+}
+
+#[test]
+fn peeks_top() {
+    let mut s: MinMaxStack<i32, 8> = MinMaxStack::new();
+    assert!(s.try_peek().is_err());
+    s.push(4);
+    s.push(7);
+    assert_eq!(7, s.peek());
+    assert_eq!(2, s.len());
+}
+
+#[test]
+fn fails_on_empty_stack() {
+    let mut s: MinMaxStack<i32, 1> = MinMaxStack::new();
+    assert!(s.try_min().err().unwrap().contains("left"));
+    assert!(s.try_max().is_err());
+    assert!(s.try_pop().is_err());
+    assert!(s.try_push(1).is_ok());
+    assert!(s.try_push(2).err().unwrap().contains("space"));
+    assert_eq!(Ok(1), s.try_min());
+}
+
+#[test]
+#[should_panic(expected = "No more items left in the stack")]
+fn panics_on_min_of_empty_stack() {
+    let s: MinMaxStack<i32, 1> = MinMaxStack::new();
+    let _ = s.min();
+}
+
+#[cfg(test)]
+use proptest::prelude::*;
+
+#[cfg(test)]
+proptest! {
+    #[test]
+    fn matches_scanning(
+        ops in proptest::collection::vec(proptest::option::of(any::<i16>()), 0..256)
+    ) {
+        let mut s: MinMaxStack<i16, 64> = MinMaxStack::new();
+        for op in ops {
+            if let Some(v) = op {
+                if s.try_push(v).is_err() {
+                    prop_assert_eq!(64, s.len());
+                }
+            } else {
+                prop_assert_eq!(s.iter().last().copied(), s.try_pop().ok());
+            }
+            prop_assert_eq!(s.iter().min().copied(), s.try_min().ok());
+            prop_assert_eq!(s.iter().max().copied(), s.try_max().ok());
+        }
+    }
+}
+
+#[test]
+fn clears_everything() {
+    let mut s: MinMaxStack<i32, 4> = MinMaxStack::new();
+    s.push(1);
+    s.clear();
+    s.push(5);
+    assert_eq!(5, s.min());
+}
+
+#[cfg(test)]
+#[derive(Clone, Copy, Debug, PartialOrd, Ord)]
+struct Liar(u8);
+
+#[cfg(test)]
+impl PartialEq for Liar {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+impl Eq for Liar {}
+
+#[test]
+fn survives_broken_equality() {
+    let mut s: MinMaxStack<Liar, 4> = MinMaxStack::new();
+    s.push(Liar(1));
+    s.push(Liar(2));
+    s.pop();
+    s.pop();
+    s.push(Liar(3));
+    assert!(s.try_pop().is_ok());
+    assert!(s.try_min().is_err());
+    assert!(s.try_max().is_err());
+    assert!(s.try_pop().is_err());
+}
+
+#[test]
+#[should_panic(expected = "No more items left in the stack")]
+fn panics_on_min_after_broken_equality() {
+    let mut s: MinMaxStack<Liar, 4> = MinMaxStack::new();
+    s.push(Liar(1));
+    s.push(Liar(2));
+    s.pop();
+    let _ = s.min();
+}
@@ -0,0 +1,434 @@
+// Copyright (c) 2023 Yegor Bugayenko
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{
+    Decreasing, Evicted, Increasing, MonotonicStack, Order, Stack, StrictlyDecreasing,
+    StrictlyIncreasing,
+};
+use std::marker::PhantomData;
+use std::ops::Deref;
+
+impl<V: PartialOrd> Order<V> for Increasing {
+    #[inline]
+    fn keeps(below: &V, above: &V) -> bool {
+        below <= above
+    }
+}
+
+impl<V: PartialOrd> Order<V> for StrictlyIncreasing {
+    #[inline]
+    fn keeps(below: &V, above: &V) -> bool {
+        below < above
+    }
+}
+
+impl<V: PartialOrd> Order<V> for Decreasing {
+    #[inline]
+    fn keeps(below: &V, above: &V) -> bool {
+        below >= above
+    }
+}
+
+impl<V: PartialOrd> Order<V> for StrictlyDecreasing {
+    #[inline]
+    fn keeps(below: &V, above: &V) -> bool {
+        below > above
+    }
+}
+
+impl<V: Copy, const N: usize, O: Order<V>> Default for MonotonicStack<V, N, O> {
+    /// Make a default empty [`MonotonicStack`].
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<V: Copy, const N: usize, O: Order<V>> Deref for MonotonicStack<V, N, O> {
+    type Target = Stack<V, N>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.stack
+    }
+}
+
+impl<V: Copy, const N: usize, O: Order<V>> Iterator for Evicted<'_, V, N, O> {
+    type Item = V;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.pushed {
+            return None;
+        }
+        let s = &mut self.stack.stack;
+        unsafe {
+            if s.next > 0 && !O::keeps(&s.items[s.next - 1], &self.v) {
+                Some(s.pop_unchecked())
+            } else {
+                self.pushed = true;
+                if self.checked {
+                    assert!(s.next < N, "No more space left in the stack");
+                }
+                s.push_unchecked(self.v);
+                None
+            }
+        }
+    }
+}
+
+impl<V: Copy, const N: usize, O: Order<V>> Drop for Evicted<'_, V, N, O> {
+    /// Evict the rest and push the element.
+    #[inline]
+    fn drop(&mut self) {
+        while self.next().is_some() {}
+    }
+}
+
+impl<V: Copy, const N: usize, O: Order<V>> MonotonicStack<V, N, O> {
+    /// Make it.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            stack: Stack::new(),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Will there be space for the element, after evictions?
+    #[inline]
+    fn fits(&self, v: &V) -> bool {
+        let s = &self.stack;
+        s.next < N || (s.next > 0 && !O::keeps(&s.items[s.next - 1], v))
+    }
+
+    /// Push new element into it, evicting all elements that violate
+    /// the order.
+    ///
+    /// # Safety
+    ///
+    /// It may lead to undefined behavior, if you go over the boundary.
+    #[inline]
+    pub const unsafe fn push_unchecked(&mut self, v: V) -> Evicted<'_, V, N, O> {
+        Evicted {
+            stack: self,
+            v,
+            pushed: false,
+            checked: false,
+        }
+    }
+
+    /// Push new element into it, evicting all elements that violate
+    /// the order.
+    ///
+    /// # Panics
+    ///
+    /// If there is no more space in the stack, even after evictions,
+    /// it will panic.
+    #[inline]
+    pub fn push(&mut self, v: V) -> Evicted<'_, V, N, O> {
+        assert!(self.fits(&v), "No more space left in the stack");
+        Evicted {
+            stack: self,
+            v,
+            pushed: false,
+            checked: true,
+        }
+    }
+
+    /// Makes an attempt to push a new element into the stack, evicting
+    /// all elements that violate the order.
+    ///
+    /// # Errors
+    ///
+    /// If there is not enough space in the stack, even after evictions,
+    /// `Err` is returned and nothing is evicted.
+    #[inline]
+    pub fn try_push(&mut self, v: V) -> Result<Evicted<'_, V, N, O>, String> {
+        if self.fits(&v) {
+            Ok(self.push(v))
+        } else {
+            Err(format!("There are no space left in the stack of {N}"))
+        }
+    }
+
+    /// Push new element into it, giving all evicted elements to the
+    /// function, from top to bottom.
+    ///
+    /// # Panics
+    ///
+    /// If there is no more space in the stack, even after evictions,
+    /// it will panic.
+    #[inline]
+    pub fn push_with<F: FnMut(V)>(&mut self, v: V, f: F) {
+        self.push(v).for_each(f);
+    }
+
+    /// Pop a element from it.
+    ///
+    /// # Safety
+    ///
+    /// If there are no items in the array, the result is undefined.
+    #[inline]
+    pub const unsafe fn pop_unchecked(&mut self) -> V {
+        self.stack.pop_unchecked()
+    }
+
+    /// Pop a element from it.
+    ///
+    /// # Panics
+    ///
+    /// If there are no items in the array, it will panic.
+    #[inline]
+    pub fn pop(&mut self) -> V {
+        self.stack.pop()
+    }
+
+    /// Pop a element from it.
+    ///
+    /// # Errors
+    ///
+    /// If there is no more elements left, it will return `Err`.
+    #[inline]
+    pub fn try_pop(&mut self) -> Result<V, String> {
+        self.stack.try_pop()
+    }
+
+    /// Get the top element, without popping it.
+    ///
+    /// # Safety
+    ///
+    /// If there are no items in the array, the result is undefined.
+    #[inline]
+    #[must_use]
+    pub const unsafe fn peek_unchecked(&self) -> V {
+        self.stack.items.as_ptr().add(self.stack.next - 1).read()
+    }
+
+    /// Get the top element, without popping it.
+    ///
+    /// # Panics
+    ///
+    /// If there are no items in the array, it will panic.
+    #[inline]
+    #[must_use]
+    pub fn peek(&self) -> V {
+        assert!(self.stack.next > 0, "No more items left in the stack");
+        unsafe { self.peek_unchecked() }
+    }
+
+    /// Get the top element, without popping it.
+    ///
+    /// # Errors
+    ///
+    /// If there is no elements, it will return `Err`.
+    #[inline]
+    pub fn try_peek(&self) -> Result<V, String> {
+        if self.stack.next == 0 {
+            Err(format!("There are no items left in the stack of {N}"))
+        } else {
+            Ok(self.peek())
+        }
+    }
+
+    /// Clear.
+    #[inline]
+    pub const fn clear(&mut self) {
+        self.stack.clear();
+    }
+}
+
+#[cfg(test)]
+struct ByFirst;
+
+#[cfg(test)]
+impl Order<(u64, usize)> for ByFirst {
+    fn keeps(below: &(u64, usize), above: &(u64, usize)) -> bool {
+        below.0 >= above.0
+    }
+}
+
+#[test]
+fn keeps_non_strict_order() {
+    let mut s: MonotonicStack<u8, 8, Increasing> = MonotonicStack::new();
+    for v in [1, 3, 3, 2] {
+        s.push(v);
+    }
+    assert_eq!(vec![1, 2], s.iter().copied().collect::<Vec<_>>());
+    let mut d: MonotonicStack<u8, 8, Decreasing> = MonotonicStack::new();
+    for v in [5, 3, 3, 4] {
+        d.push(v);
+    }
+    assert_eq!(vec![5, 4], d.iter().copied().collect::<Vec<_>>());
+}
+
+#[test]
+fn keeps_strict_order() {
+    let mut s: MonotonicStack<u8, 8, StrictlyIncreasing> = MonotonicStack::new();
+    for v in [1, 3, 3] {
+        s.push(v);
+    }
+    assert_eq!(vec![1, 3], s.iter().copied().collect::<Vec<_>>());
+    let mut d: MonotonicStack<u8, 8, StrictlyDecreasing> = MonotonicStack::new();
+    for v in [5, 3, 3] {
+        d.push(v);
+    }
+    assert_eq!(vec![5, 3], d.iter().copied().collect::<Vec<_>>());
+}
+
+#[test]
+fn yields_evicted_from_top() {
+    let mut s: MonotonicStack<u8, 8, Increasing> = MonotonicStack::new();
+    for v in [1, 2, 3, 4] {
+        s.push(v);
+    }
+    assert_eq!(vec![4, 3], s.push(2).collect::<Vec<_>>());
+    assert_eq!(2, s.peek());
+}
+
+#[test]
+fn pushes_when_dropped_unfinished() {
+    let mut s: MonotonicStack<u8, 8, Increasing> = MonotonicStack::new();
+    for v in [1, 2, 3] {
+        s.push(v);
+    }
+    assert_eq!(Some(3), s.push(0).next());
+    assert_eq!(vec![0], s.iter().copied().collect::<Vec<_>>());
+}
+
+#[test]
+fn gives_evicted_to_callback() {
+    let mut s: MonotonicStack<u8, 8, Decreasing> = MonotonicStack::new();
+    s.push(9);
+    s.push(2);
+    s.push(1);
+    let mut sum = 0;
+    s.push_with(5, |v| sum += v);
+    assert_eq!(3, sum);
+    assert_eq!(2, s.len());
+}
+
+#[test]
+fn pushes_into_full_stack_after_eviction() {
+    let mut s: MonotonicStack<u8, 2, Increasing> = MonotonicStack::new();
+    s.push(1);
+    s.push(2);
+    assert!(s.try_push(3).err().unwrap().contains("space"));
+    assert_eq!(2, s.len());
+    assert_eq!(vec![2, 1], s.try_push(0).unwrap().collect::<Vec<_>>());
+    assert_eq!(vec![0], s.iter().copied().collect::<Vec<_>>());
+}
+
+#[test]
+#[should_panic(expected = "No more space left in the stack")]
+fn panics_when_full() {
+    let mut s: MonotonicStack<u8, 1, Increasing> = MonotonicStack::new();
+    s.push(1);
+    s.push(1);
+}
+
+#[test]
+#[should_panic(expected = "No more space left in the stack")]
+fn panics_when_pushing_into_zero_capacity() {
+    let mut s: MonotonicStack<u8, 0, Increasing> = MonotonicStack::new();
+    s.push(1);
+}
+
+#[test]
+fn refuses_to_push_into_zero_capacity() {
+    let mut s: MonotonicStack<u8, 0, StrictlyDecreasing> = MonotonicStack::new();
+    assert!(s.try_push(1).is_err());
+    assert!(s.is_empty());
+}
+
+#[test]
+fn finds_next_greater_elements() {
+    let xs = [2, 1, 2, 4, 3, 3];
+    let mut found = [None; 6];
+    let mut s: MonotonicStack<(u64, usize), 8, ByFirst> = MonotonicStack::new();
+    for (i, x) in xs.iter().enumerate() {
+        for (_, j) in s.push((*x, i)) {
+            found[j] = Some(*x);
+        }
+    }
+    assert_eq!([Some(4), Some(2), Some(4), None, None, None], found);
+}
+
+#[test]
+fn calculates_stock_span() {
+    let prices = [100, 80, 60, 70, 60, 75, 85];
+    let mut spans = vec![];
+    let mut s: MonotonicStack<(u64, usize), 8, StrictlyDecreasing> = MonotonicStack::new();
+    for (i, p) in prices.iter().enumerate() {
+        s.push((*p, i));
+        let below = s.len().checked_sub(2).and_then(|k| s.iter().nth(k));
+        spans.push(below.map_or(i + 1, |(_, j)| i - j));
+    }
+    assert_eq!(vec![1, 1, 1, 2, 1, 4, 6], spans);
+}
+
+#[test]
+fn finds_largest_rectangle_in_histogram() {
+    let heights = [2, 1, 5, 6, 2, 3];
+    let mut best = 0;
+    let mut s: MonotonicStack<usize, 8, StrictlyIncreasing> = MonotonicStack::new();
+    let mut bars: Stack<(usize, usize), 8> = Stack::new();
+    for (i, h) in heights.iter().copied().chain([0]).enumerate() {
+        let mut start = i;
+        for _ in s.push(h) {
+            let (from, tall) = bars.pop();
+            best = best.max(tall * (i - from));
+            start = from;
+        }
+        bars.push((start, h));
+    }
+    assert_eq!(10, best);
+}
+
+#[cfg(test)]
+thread_local! {
+    static CALLS: std::cell::Cell<usize> = const { std::cell::Cell::new(0) };
+}
+
+/// An order that evicts on the first call only, and keeps everything later.
+#[cfg(test)]
+struct Fickle;
+
+#[cfg(test)]
+impl Order<u8> for Fickle {
+    fn keeps(_: &u8, _: &u8) -> bool {
+        CALLS.with(|c| {
+            c.set(c.get() + 1);
+            c.get() > 1
+        })
+    }
+}
+
+#[test]
+#[should_panic(expected = "No more space left in the stack")]
+fn panics_when_order_changes_its_mind() {
+    let mut s: MonotonicStack<u8, 2, Fickle> = MonotonicStack::new();
+    unsafe {
+        s.stack.push_unchecked(1);
+        s.stack.push_unchecked(2);
+    }
+    s.push(3).for_each(drop);
+}
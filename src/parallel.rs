@@ -0,0 +1,201 @@
+// Copyright (c) 2023 Yegor Bugayenko
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::Stack;
+use rayon::iter::{IntoParallelIterator, ParallelExtend, ParallelIterator};
+
+impl<'a, V: Copy + Sync, const N: usize> IntoParallelIterator for &'a Stack<V, N> {
+    type Iter = rayon::slice::Iter<'a, V>;
+    type Item = &'a V;
+
+    /// Iterate its elements in parallel.
+    fn into_par_iter(self) -> Self::Iter {
+        self.items[..self.next].into_par_iter()
+    }
+}
+
+impl<'a, V: Copy + Send, const N: usize> IntoParallelIterator for &'a mut Stack<V, N> {
+    type Iter = rayon::slice::IterMut<'a, V>;
+    type Item = &'a mut V;
+
+    /// Iterate its elements in parallel, with mutable access to them.
+    fn into_par_iter(self) -> Self::Iter {
+        self.items[..self.next].into_par_iter()
+    }
+}
+
+impl<V: Copy + Send, const N: usize> IntoParallelIterator for Stack<V, N> {
+    type Iter = rayon::vec::IntoIter<V>;
+    type Item = V;
+
+    /// Iterate its elements in parallel, after copying them to the heap,
+    /// since rayon can't take ownership of a partially filled array.
+    fn into_par_iter(self) -> Self::Iter {
+        self.items[..self.next].to_vec().into_par_iter()
+    }
+}
+
+/// Collect at most `n` first elements of the parallel iterator, in its
+/// order, never keeping more than `n` of them in any part of it.
+fn first<V: Send, I: IntoParallelIterator<Item = V>>(par_iter: I, n: usize) -> Vec<V> {
+    par_iter
+        .into_par_iter()
+        .fold(Vec::new, move |mut acc, v| {
+            if acc.len() < n {
+                acc.push(v);
+            }
+            acc
+        })
+        .reduce(Vec::new, move |mut acc, rest| {
+            let room = n - acc.len();
+            acc.extend(rest.into_iter().take(room));
+            acc
+        })
+}
+
+impl<V: Copy + Send, const N: usize> ParallelExtend<V> for Stack<V, N> {
+    /// Push the elements, in the order of the iterator, while there is
+    /// space in the stack; the ones that don't fit are silently dropped.
+    ///
+    /// The iterator is still consumed entirely, but no more elements
+    /// than the stack has room for are kept on the heap on the way.
+    /// Use [`Stack::try_par_extend`] to detect the overflow.
+    fn par_extend<I: IntoParallelIterator<Item = V>>(&mut self, par_iter: I) {
+        for v in first(par_iter, N - self.next) {
+            unsafe { self.push_unchecked(v) };
+        }
+    }
+}
+
+impl<V: Copy + Send, const N: usize> Stack<V, N> {
+    /// Makes an attempt to push all elements of the parallel iterator,
+    /// in its order.
+    ///
+    /// # Errors
+    ///
+    /// If there is not enough space for all of them, `Err` is returned
+    /// and the stack stays untouched.
+    pub fn try_par_extend<I: IntoParallelIterator<Item = V>>(
+        &mut self,
+        par_iter: I,
+    ) -> Result<(), String> {
+        let items = first(par_iter, N - self.next + 1);
+        if items.len() > N - self.next {
+            return Err(format!("There are no space left in the stack of {N}"));
+        }
+        for v in items {
+            unsafe { self.push_unchecked(v) };
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+use rayon::iter::IndexedParallelIterator;
+
+#[cfg(test)]
+fn sample() -> Stack<f64, 4096> {
+    Stack::from_fn(4000, |i| f64::from(u16::try_from(i).unwrap()) * 0.5)
+}
+
+#[test]
+fn sums_in_parallel() {
+    let s = sample();
+    let expected: f64 = s.iter().sum();
+    let actual: f64 = (&s).into_par_iter().sum();
+    assert!((expected - actual).abs() < 1e-6);
+}
+
+#[test]
+fn maps_in_parallel_by_ref() {
+    let s = sample();
+    let expected: Vec<f64> = s.iter().map(|v| v * v).collect();
+    let actual: Vec<f64> = (&s).into_par_iter().map(|v| v * v).collect();
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn modifies_in_parallel() {
+    let mut s = sample();
+    let expected: Vec<f64> = s.iter().map(|v| v + 1.0).collect();
+    (&mut s).into_par_iter().for_each(|v| *v += 1.0);
+    assert_eq!(expected, s.into_iter().collect::<Vec<_>>());
+}
+
+#[test]
+fn consumes_in_parallel() {
+    let s = sample();
+    let expected: Vec<f64> = s.clone().into_iter().collect();
+    let actual: Vec<f64> = s.into_par_iter().collect();
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn iterates_empty_stack_in_parallel() {
+    let s: Stack<f64, 8> = Stack::new();
+    assert_eq!(0, (&s).into_par_iter().count());
+    assert_eq!(0, s.into_par_iter().len());
+}
+
+#[test]
+fn extends_in_parallel() {
+    let mut s: Stack<u32, 4096> = Stack::new();
+    s.push(42);
+    s.par_extend((0..1000u32).into_par_iter().map(|v| v * 2));
+    let expected: Vec<u32> = std::iter::once(42)
+        .chain((0..1000).map(|v| v * 2))
+        .collect();
+    assert_eq!(expected, s.into_iter().collect::<Vec<_>>());
+}
+
+#[test]
+fn drops_what_does_not_fit_in_parallel() {
+    let mut s: Stack<u32, 8> = Stack::new();
+    s.par_extend((0..100u32).into_par_iter());
+    assert!(s.is_full());
+    assert_eq!(
+        (0..8).collect::<Vec<_>>(),
+        s.into_iter().collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn keeps_order_when_dropping_in_parallel() {
+    let mut s: Stack<u32, 64> = Stack::new();
+    s.push(7);
+    s.par_extend(
+        (0..1_000_000u32)
+            .into_par_iter()
+            .with_max_len(16)
+            .filter(|v| v % 2 == 0),
+    );
+    let expected: Vec<u32> = std::iter::once(7).chain((0..126).step_by(2)).collect();
+    assert_eq!(expected, s.into_iter().collect::<Vec<_>>());
+}
+
+#[test]
+fn tries_to_extend_in_parallel() {
+    let mut s: Stack<u32, 8> = Stack::new();
+    s.push(1);
+    assert!(s.try_par_extend((0..8u32).into_par_iter()).is_err());
+    assert_eq!(1, s.len());
+    assert!(s.try_par_extend((0..7u32).into_par_iter()).is_ok());
+    assert!(s.is_full());
+}
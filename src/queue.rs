@@ -0,0 +1,220 @@
+// Copyright (c) 2023 Yegor Bugayenko
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{Deque, DequeIntoIter, DequeIter, Queue};
+
+impl<V: Copy, const N: usize> Default for Queue<V, N> {
+    /// Make a default empty [`Queue`].
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<V: Copy, const N: usize> Queue<V, N> {
+    /// Make it.
+    ///
+    /// The size of the queue is defined by the generic argument.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            deque: Deque::new(),
+        }
+    }
+
+    /// Get the capacity.
+    #[inline]
+    #[must_use]
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Push new element to the back.
+    ///
+    /// # Safety
+    ///
+    /// It may lead to undefined behavior, if you go over the boundary.
+    #[inline]
+    pub const unsafe fn push_unchecked(&mut self, v: V) {
+        self.deque.push_back_unchecked(v);
+    }
+
+    /// Push new element to the back.
+    ///
+    /// # Panics
+    ///
+    /// If there is no more space in the queue, it will panic.
+    #[inline]
+    pub fn push(&mut self, v: V) {
+        assert!(self.deque.len < N, "No more space left in the queue");
+        unsafe {
+            self.push_unchecked(v);
+        }
+    }
+
+    /// Makes an attempt to push a new element to the back.
+    ///
+    /// # Errors
+    ///
+    /// If there is not enough space in the queue, `Err` is returned.
+    #[inline]
+    pub fn try_push(&mut self, v: V) -> Result<(), String> {
+        if self.deque.len < N {
+            self.push(v);
+            Ok(())
+        } else {
+            Err(format!("There are no space left in the queue of {N}"))
+        }
+    }
+
+    /// Pop an element from the front.
+    ///
+    /// # Safety
+    ///
+    /// If there are no items in the queue, the result is undefined.
+    #[inline]
+    pub const unsafe fn pop_unchecked(&mut self) -> V {
+        self.deque.pop_front_unchecked()
+    }
+
+    /// Pop an element from the front.
+    ///
+    /// # Panics
+    ///
+    /// If there are no items in the queue, it will panic.
+    #[inline]
+    pub fn pop(&mut self) -> V {
+        assert!(self.deque.len > 0, "No more items left in the queue");
+        unsafe { self.pop_unchecked() }
+    }
+
+    /// Pop an element from the front.
+    ///
+    /// # Errors
+    ///
+    /// If there is no more elements left, it will return `Err`.
+    #[inline]
+    pub fn try_pop(&mut self) -> Result<V, String> {
+        if self.deque.len == 0 {
+            Err(format!("There are no items left in the queue of {N}"))
+        } else {
+            Ok(self.pop())
+        }
+    }
+
+    /// Get the element at the front, which will be popped next, if any.
+    #[inline]
+    #[must_use]
+    pub const fn peek(&self) -> Option<&V> {
+        self.deque.front()
+    }
+
+    /// Clear.
+    #[inline]
+    pub const fn clear(&mut self) {
+        self.deque.clear();
+    }
+
+    /// Is it empty.
+    #[inline]
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.deque.is_empty()
+    }
+
+    /// Length of it.
+    #[inline]
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.deque.len()
+    }
+
+    /// Iterate them, from front to back.
+    #[inline]
+    #[must_use]
+    pub const fn iter(&self) -> DequeIter<'_, V, N> {
+        self.deque.iter()
+    }
+}
+
+impl<'a, V: Copy, const N: usize> IntoIterator for &'a Queue<V, N> {
+    type Item = &'a V;
+    type IntoIter = DequeIter<'a, V, N>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<V: Copy, const N: usize> IntoIterator for Queue<V, N> {
+    type Item = V;
+    type IntoIter = DequeIntoIter<V, N>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.deque.into_iter()
+    }
+}
+
+#[test]
+fn pops_in_fifo_order() {
+    let mut q: Queue<u64, 4> = Queue::new();
+    q.push(1);
+    q.push(2);
+    q.push(3);
+    assert_eq!(Some(&1), q.peek());
+    assert_eq!(1, q.pop());
+    assert_eq!(2, q.pop());
+    q.push(4);
+    assert_eq!(vec![3, 4], q.into_iter().collect::<Vec<_>>());
+}
+
+#[test]
+fn fails_when_full_or_empty() {
+    let mut q: Queue<u64, 1> = Queue::new();
+    assert!(q.try_pop().err().unwrap().contains("left"));
+    assert!(q.try_push(1).is_ok());
+    assert!(q.try_push(2).err().unwrap().contains("space"));
+    assert_eq!(Ok(1), q.try_pop());
+}
+
+#[test]
+#[should_panic(expected = "No more space left in the queue")]
+fn panics_when_full() {
+    let mut q: Queue<u64, 1> = Queue::new();
+    q.push(1);
+    q.push(2);
+}
+
+#[test]
+fn cycles_through_capacity() {
+    let mut q: Queue<usize, 4> = Queue::new();
+    for i in 0..100 {
+        q.push(i);
+        if q.len() == q.capacity() {
+            q.pop();
+        }
+    }
+    assert_eq!(vec![97, 98, 99], q.iter().copied().collect::<Vec<_>>());
+    q.clear();
+    assert!(q.is_empty());
+}
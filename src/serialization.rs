@@ -18,9 +18,9 @@
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 // SOFTWARE.
 
-use crate::Stack;
-use serde::de::{SeqAccess, Visitor};
-use serde::ser::SerializeSeq;
+use crate::{Deque, DualStack, Queue, Stack, StackMap, StackSet, StackString};
+use serde::de::{Error, MapAccess, SeqAccess, Visitor};
+use serde::ser::{SerializeSeq, SerializeTuple};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::fmt::Formatter;
 use std::marker::PhantomData;
@@ -31,7 +31,7 @@ impl<V: Serialize + Copy, const N: usize> Serialize for Stack<V, N> {
         S: Serializer,
     {
         let mut map = serializer.serialize_seq(Some(self.next))?;
-        for v in self.iter() {
+        for v in self {
             map.serialize_element(&v)?;
         }
         map.end()
@@ -53,7 +53,7 @@ impl<'de, V: Copy + Deserialize<'de>, const N: usize> Visitor<'de> for Vi<V, N>
     {
         let mut p: Self::Value = Stack::new();
         while let Some(v) = access.next_element()? {
-            p.push(v);
+            p.try_push(v).map_err(Error::custom)?;
         }
         Ok(p)
     }
@@ -68,6 +68,267 @@ impl<'de, V: Copy + Deserialize<'de>, const N: usize> Deserialize<'de> for Stack
     }
 }
 
+/// One side of a [`DualStack`], to be serialized as a sequence.
+struct Half<'a, V: Copy, const N: usize, const LEFT: bool>(&'a DualStack<V, N>);
+
+impl<V: Serialize + Copy, const N: usize, const LEFT: bool> Serialize for Half<'_, V, N, LEFT> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if LEFT {
+            serializer.collect_seq(self.0.iter_left())
+        } else {
+            serializer.collect_seq(self.0.iter_right())
+        }
+    }
+}
+
+impl<V: Serialize + Copy, const N: usize> Serialize for DualStack<V, N> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut tuple = serializer.serialize_tuple(2)?;
+        tuple.serialize_element(&Half::<V, N, true>(self))?;
+        tuple.serialize_element(&Half::<V, N, false>(self))?;
+        tuple.end()
+    }
+}
+
+struct DualVi<V, const N: usize>(PhantomData<V>);
+
+impl<'de, V: Copy + Deserialize<'de>, const N: usize> Visitor<'de> for DualVi<V, N> {
+    type Value = DualStack<V, N>;
+
+    fn expecting(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+        formatter.write_str("a DualStack")
+    }
+
+    fn visit_seq<A>(self, mut access: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let left: Stack<V, N> = access
+            .next_element()?
+            .ok_or_else(|| Error::invalid_length(0, &self))?;
+        let right: Stack<V, N> = access
+            .next_element()?
+            .ok_or_else(|| Error::invalid_length(1, &self))?;
+        if left.len() + right.len() > N {
+            return Err(Error::custom(format!(
+                "There are no space left in the stack of {N}"
+            )));
+        }
+        let mut p: Self::Value = DualStack::new();
+        for v in left {
+            p.left().push(v);
+        }
+        for v in right {
+            p.right().push(v);
+        }
+        Ok(p)
+    }
+}
+
+impl<'de, V: Copy + Deserialize<'de>, const N: usize> Deserialize<'de> for DualStack<V, N> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_tuple(2, DualVi(PhantomData))
+    }
+}
+
+impl<V: Serialize + Copy, const N: usize> Serialize for Deque<V, N> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_seq(self)
+    }
+}
+
+impl<V: Serialize + Copy, const N: usize> Serialize for Queue<V, N> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.deque.serialize(serializer)
+    }
+}
+
+struct DequeVi<V, const N: usize>(PhantomData<V>);
+
+impl<'de, V: Copy + Deserialize<'de>, const N: usize> Visitor<'de> for DequeVi<V, N> {
+    type Value = Deque<V, N>;
+
+    fn expecting(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+        formatter.write_str("a Deque")
+    }
+
+    fn visit_seq<A>(self, mut access: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut p: Self::Value = Deque::new();
+        while let Some(v) = access.next_element()? {
+            p.try_push_back(v).map_err(Error::custom)?;
+        }
+        Ok(p)
+    }
+}
+
+impl<'de, V: Copy + Deserialize<'de>, const N: usize> Deserialize<'de> for Deque<V, N> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(DequeVi(PhantomData))
+    }
+}
+
+impl<'de, V: Copy + Deserialize<'de>, const N: usize> Deserialize<'de> for Queue<V, N> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(Self {
+            deque: Deque::deserialize(deserializer)?,
+        })
+    }
+}
+
+impl<V: Serialize + Ord + Copy, const N: usize> Serialize for StackSet<V, N> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut seq = serializer.serialize_seq(Some(self.len()))?;
+        for v in self.iter() {
+            seq.serialize_element(v)?;
+        }
+        seq.end()
+    }
+}
+
+struct SetVi<V, const N: usize>(PhantomData<V>);
+
+impl<'de, V: Ord + Copy + Deserialize<'de>, const N: usize> Visitor<'de> for SetVi<V, N> {
+    type Value = StackSet<V, N>;
+
+    fn expecting(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+        formatter.write_str("a StackSet")
+    }
+
+    fn visit_seq<A>(self, mut access: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut p: Self::Value = StackSet::new();
+        while let Some(v) = access.next_element()? {
+            p.try_insert(v).map_err(Error::custom)?;
+        }
+        Ok(p)
+    }
+}
+
+impl<'de, V: Ord + Copy + Deserialize<'de>, const N: usize> Deserialize<'de> for StackSet<V, N> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(SetVi(PhantomData))
+    }
+}
+
+impl<K: Serialize + Ord + Copy, V: Serialize + Copy, const N: usize> Serialize
+    for StackMap<K, V, N>
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_map(self)
+    }
+}
+
+struct MapVi<K, V, const N: usize>(PhantomData<(K, V)>);
+
+impl<'de, K: Ord + Copy + Deserialize<'de>, V: Copy + Deserialize<'de>, const N: usize> Visitor<'de>
+    for MapVi<K, V, N>
+{
+    type Value = StackMap<K, V, N>;
+
+    fn expecting(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+        formatter.write_str("a StackMap")
+    }
+
+    fn visit_map<A>(self, mut access: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut p: Self::Value = StackMap::new();
+        while let Some((k, v)) = access.next_entry()? {
+            p.try_insert(k, v).map_err(Error::custom)?;
+        }
+        Ok(p)
+    }
+}
+
+impl<'de, K: Ord + Copy + Deserialize<'de>, V: Copy + Deserialize<'de>, const N: usize>
+    Deserialize<'de> for StackMap<K, V, N>
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(MapVi(PhantomData))
+    }
+}
+
+impl<const N: usize> Serialize for StackString<N> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+struct StringVi<const N: usize>;
+
+impl<const N: usize> Visitor<'_> for StringVi<N> {
+    type Value = StackString<N>;
+
+    fn expecting(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(formatter, "a string of at most {N} bytes")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        v.parse().map_err(Error::custom)
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        StackString::from_utf8(v).map_err(Error::custom)
+    }
+}
+
+impl<'de, const N: usize> Deserialize<'de> for StackString<N> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(StringVi)
+    }
+}
+
 #[cfg(test)]
 use bincode::{deserialize, serialize};
 
@@ -79,3 +340,94 @@ fn serialize_and_deserialize() {
     let after: Stack<u8, 8> = deserialize(&bytes).unwrap();
     assert_eq!(42, after.into_iter().next().unwrap());
 }
+
+#[test]
+fn refuses_to_deserialize_too_many() {
+    let mut before: Stack<u8, 8> = Stack::new();
+    before.push(1);
+    before.push(2);
+    before.push(3);
+    let bytes: Vec<u8> = serialize(&before).unwrap();
+    assert!(deserialize::<Stack<u8, 2>>(&bytes).is_err());
+}
+
+#[test]
+fn serialize_and_deserialize_dual_stack() {
+    let mut before: DualStack<u8, 8> = DualStack::new();
+    before.left().push(1);
+    before.left().push(2);
+    before.right().push(3);
+    let bytes: Vec<u8> = serialize(&before).unwrap();
+    let mut after: DualStack<u8, 8> = deserialize(&bytes).unwrap();
+    assert_eq!(2, after.left().pop());
+    assert_eq!(1, after.left().pop());
+    assert_eq!(3, after.right().pop());
+}
+
+#[test]
+fn fails_to_deserialize_overflowing_dual_stack() {
+    let mut before: DualStack<u8, 4> = DualStack::new();
+    before.left().push(1);
+    before.right().push(2);
+    before.right().push(3);
+    let bytes: Vec<u8> = serialize(&before).unwrap();
+    assert!(deserialize::<DualStack<u8, 2>>(&bytes).is_err());
+}
+
+#[test]
+fn serialize_and_deserialize_deque() {
+    let mut before: Deque<u8, 8> = Deque::new();
+    before.push_back(2);
+    before.push_front(1);
+    let bytes: Vec<u8> = serialize(&before).unwrap();
+    let after: Deque<u8, 8> = deserialize(&bytes).unwrap();
+    assert_eq!(vec![1, 2], after.into_iter().collect::<Vec<_>>());
+    assert!(deserialize::<Deque<u8, 1>>(&bytes).is_err());
+}
+
+#[test]
+fn serialize_and_deserialize_queue() {
+    let mut before: Queue<u8, 8> = Queue::new();
+    before.push(42);
+    let bytes: Vec<u8> = serialize(&before).unwrap();
+    let mut after: Queue<u8, 8> = deserialize(&bytes).unwrap();
+    assert_eq!(42, after.pop());
+}
+
+#[test]
+fn serialize_and_deserialize_set() {
+    let mut before: StackSet<u8, 8> = StackSet::new();
+    before.insert(7);
+    before.insert(3);
+    let bytes: Vec<u8> = serialize(&before).unwrap();
+    let after: StackSet<u8, 8> = deserialize(&bytes).unwrap();
+    assert_eq!(vec![3, 7], after.iter().copied().collect::<Vec<_>>());
+    assert!(deserialize::<StackSet<u8, 1>>(&bytes).is_err());
+}
+
+#[test]
+fn serialize_and_deserialize_map() {
+    let mut before: StackMap<u8, u16, 8> = StackMap::new();
+    before.insert(2, 200);
+    before.insert(1, 100);
+    let bytes: Vec<u8> = serialize(&before).unwrap();
+    let after: StackMap<u8, u16, 8> = deserialize(&bytes).unwrap();
+    assert_eq!(Some(&200), after.get(&2));
+    assert_eq!(2, after.len());
+    assert!(deserialize::<StackMap<u8, u16, 1>>(&bytes).is_err());
+}
+
+#[test]
+fn serialize_and_deserialize_string() {
+    let before: StackString<8> = "пр".parse().unwrap();
+    let bytes: Vec<u8> = serialize(&before).unwrap();
+    let after: StackString<8> = deserialize(&bytes).unwrap();
+    assert_eq!("пр", after.as_str());
+    assert!(deserialize::<StackString<2>>(&bytes).is_err());
+}
+
+#[test]
+fn refuse_to_deserialize_invalid_string() {
+    let bytes: Vec<u8> = serialize(&[0xFF_u8, 0xFE].as_slice()).unwrap();
+    assert!(deserialize::<StackString<8>>(&bytes).is_err());
+}
@@ -0,0 +1,334 @@
+// Copyright (c) 2023 Yegor Bugayenko
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{Stack, StackSet};
+use std::ops::{Bound, Deref, RangeBounds};
+
+/// Find the positions of the first and after the last items,
+/// whose keys are in the range.
+#[allow(clippy::redundant_pub_crate)]
+pub(crate) fn span<K: Ord, T, R: RangeBounds<K>>(
+    items: &[T],
+    key: impl Fn(&T) -> &K,
+    range: &R,
+) -> (usize, usize) {
+    let lo = match range.start_bound() {
+        Bound::Included(x) => items.partition_point(|e| key(e) < x),
+        Bound::Excluded(x) => items.partition_point(|e| key(e) <= x),
+        Bound::Unbounded => 0,
+    };
+    let hi = match range.end_bound() {
+        Bound::Included(x) => items.partition_point(|e| key(e) <= x),
+        Bound::Excluded(x) => items.partition_point(|e| key(e) < x),
+        Bound::Unbounded => items.len(),
+    };
+    (lo, hi.max(lo))
+}
+
+impl<V: Ord + Copy, const N: usize> Default for StackSet<V, N> {
+    /// Make a default empty [`StackSet`].
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<V: Ord + Copy, const N: usize> Deref for StackSet<V, N> {
+    type Target = Stack<V, N>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.stack
+    }
+}
+
+impl<V: Ord + Copy, const N: usize> StackSet<V, N> {
+    /// Make it.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            stack: Stack::new(),
+        }
+    }
+
+    /// All elements, in ascending order.
+    #[inline]
+    fn items(&self) -> &[V] {
+        &self.stack.items[..self.stack.next]
+    }
+
+    /// Insert new element, unless it is already there.
+    ///
+    /// Returns `true` if the element was inserted.
+    ///
+    /// # Safety
+    ///
+    /// It may lead to undefined behavior, if you go over the boundary.
+    #[inline]
+    pub unsafe fn insert_unchecked(&mut self, v: V) -> bool {
+        match self.items().binary_search(&v) {
+            Ok(_) => false,
+            Err(i) => {
+                self.stack.insert_unchecked(i, v);
+                true
+            }
+        }
+    }
+
+    /// Insert new element, unless it is already there.
+    ///
+    /// Returns `true` if the element was inserted.
+    ///
+    /// # Panics
+    ///
+    /// If the element is not there and there is no more space
+    /// in the set, it will panic.
+    #[inline]
+    pub fn insert(&mut self, v: V) -> bool {
+        match self.items().binary_search(&v) {
+            Ok(_) => false,
+            Err(i) => {
+                assert!(self.stack.next < N, "No more space left in the set");
+                unsafe { self.stack.insert_unchecked(i, v) };
+                true
+            }
+        }
+    }
+
+    /// Makes an attempt to insert new element, unless it is already there.
+    ///
+    /// Returns `Ok(true)` if the element was inserted.
+    ///
+    /// # Errors
+    ///
+    /// If the element is not there and there is not enough space
+    /// in the set, `Err` is returned.
+    #[inline]
+    pub fn try_insert(&mut self, v: V) -> Result<bool, String> {
+        match self.items().binary_search(&v) {
+            Ok(_) => Ok(false),
+            Err(i) => {
+                if self.stack.next < N {
+                    unsafe { self.stack.insert_unchecked(i, v) };
+                    Ok(true)
+                } else {
+                    Err(format!("There are no space left in the set of {N}"))
+                }
+            }
+        }
+    }
+
+    /// Remove the element.
+    ///
+    /// Returns `true` if the element was there.
+    #[inline]
+    pub fn remove(&mut self, v: &V) -> bool {
+        match self.items().binary_search(v) {
+            Ok(i) => {
+                unsafe { self.stack.remove_unchecked(i) };
+                true
+            }
+            Err(_) => false,
+        }
+    }
+
+    /// Is the element there.
+    #[inline]
+    #[must_use]
+    pub fn contains(&self, v: &V) -> bool {
+        self.items().binary_search(v).is_ok()
+    }
+
+    /// Get the element equal to the given one, if any.
+    #[inline]
+    #[must_use]
+    pub fn get(&self, v: &V) -> Option<&V> {
+        self.items()
+            .binary_search(v)
+            .ok()
+            .map(|i| &self.stack.items[i])
+    }
+
+    /// Get the smallest element, if any.
+    #[inline]
+    #[must_use]
+    pub fn first(&self) -> Option<&V> {
+        self.items().first()
+    }
+
+    /// Get the largest element, if any.
+    #[inline]
+    #[must_use]
+    pub fn last(&self) -> Option<&V> {
+        self.items().last()
+    }
+
+    /// Iterate elements in the range, in ascending order.
+    #[inline]
+    pub fn range<R: RangeBounds<V>>(&self, range: R) -> std::slice::Iter<'_, V> {
+        let items = self.items();
+        let (lo, hi) = span(items, |v| v, &range);
+        items[lo..hi].iter()
+    }
+
+    /// Clear.
+    #[inline]
+    pub const fn clear(&mut self) {
+        self.stack.clear();
+    }
+}
+
+#[test]
+fn keeps_elements_sorted_and_unique() {
+    let mut s: StackSet<u64, 8> = StackSet::new();
+    for v in [5, 1, 3, 1, 5, 2] {
+        s.insert(v);
+    }
+    assert_eq!(vec![1, 2, 3, 5], s.iter().copied().collect::<Vec<_>>());
+    assert_eq!(Some(&1), s.first());
+    assert_eq!(Some(&5), s.last());
+}
+
+#[test]
+fn reports_insertion() {
+    let mut s: StackSet<u64, 8> = StackSet::new();
+    assert!(s.insert(1));
+    assert!(!s.insert(1));
+}
+
+#[test]
+fn removes_elements() {
+    let mut s: StackSet<u64, 8> = StackSet::new();
+    for v in [4, 2, 6] {
+        s.insert(v);
+    }
+    assert!(s.remove(&4));
+    assert!(!s.remove(&4));
+    assert!(!s.contains(&4));
+    assert_eq!(Some(&6), s.get(&6));
+    assert_eq!(vec![2, 6], s.iter().copied().collect::<Vec<_>>());
+}
+
+#[test]
+fn queries_ranges() {
+    let mut s: StackSet<u64, 16> = StackSet::new();
+    for v in 0..10 {
+        s.insert(v * 10);
+    }
+    assert_eq!(vec![20, 30], s.range(15..40).copied().collect::<Vec<_>>());
+    assert_eq!(
+        vec![20, 30, 40],
+        s.range(20..=40).copied().collect::<Vec<_>>()
+    );
+    assert_eq!(vec![80, 90], s.range(75..).copied().collect::<Vec<_>>());
+    assert_eq!(2, s.range(..20).count());
+    let (from, to) = (50, 10);
+    assert_eq!(0, s.range(from..to).count());
+}
+
+#[test]
+fn fails_when_full() {
+    let mut s: StackSet<u64, 2> = StackSet::new();
+    assert_eq!(Ok(true), s.try_insert(1));
+    assert_eq!(Ok(true), s.try_insert(2));
+    assert_eq!(Ok(false), s.try_insert(2));
+    assert!(s.try_insert(3).err().unwrap().contains("space"));
+}
+
+#[test]
+#[should_panic(expected = "No more space left in the set")]
+fn panics_when_full() {
+    let mut s: StackSet<u64, 1> = StackSet::new();
+    s.insert(1);
+    s.insert(2);
+}
+
+/// A key, which, once armed, claims to be equal to all keys in the
+/// first few comparisons, and smaller than all of them after that.
+#[cfg(test)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[allow(clippy::redundant_pub_crate)]
+pub(crate) struct Fickle(pub(crate) u8);
+
+#[cfg(test)]
+thread_local! {
+    pub(crate) static LIES: std::cell::Cell<Option<usize>> = const { std::cell::Cell::new(None) };
+    pub(crate) static CALLS: std::cell::Cell<usize> = const { std::cell::Cell::new(0) };
+}
+
+#[cfg(test)]
+impl PartialOrd for Fickle {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(test)]
+impl Ord for Fickle {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        CALLS.with(|c| c.set(c.get() + 1));
+        LIES.with(|l| match l.get() {
+            None => self.0.cmp(&other.0),
+            Some(0) => std::cmp::Ordering::Less,
+            Some(n) => {
+                l.set(Some(n - 1));
+                std::cmp::Ordering::Equal
+            }
+        })
+    }
+}
+
+#[test]
+fn never_panics_on_try_insert_with_broken_order() {
+    let mut s: StackSet<Fickle, 2> = StackSet::new();
+    s.insert(Fickle(1));
+    s.insert(Fickle(2));
+    CALLS.with(|c| c.set(0));
+    let _ = s.contains(&Fickle(3));
+    LIES.with(|l| l.set(Some(CALLS.with(std::cell::Cell::get))));
+    let r = s.try_insert(Fickle(3));
+    LIES.with(|l| l.set(None));
+    assert_eq!(Ok(false), r);
+    assert_eq!(2, s.len());
+}
+
+#[cfg(test)]
+use proptest::prelude::*;
+
+#[cfg(test)]
+proptest! {
+    #[test]
+    fn matches_btree_set(ops in proptest::collection::vec((any::<bool>(), 0..48u8), 0..256)) {
+        let mut s: StackSet<u8, 32> = StackSet::new();
+        let mut model = std::collections::BTreeSet::new();
+        for (insert, v) in ops {
+            if insert {
+                match s.try_insert(v) {
+                    Ok(added) => prop_assert_eq!(model.insert(v), added),
+                    Err(_) => prop_assert!(model.len() == 32 && !model.contains(&v)),
+                }
+            } else {
+                prop_assert_eq!(model.remove(&v), s.remove(&v));
+            }
+            prop_assert!(model.iter().eq(s.iter()));
+        }
+    }
+}
@@ -0,0 +1,154 @@
+// Copyright (c) 2023 Yegor Bugayenko
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::Stack;
+use std::slice::{Chunks, RChunks};
+
+impl<V: Copy, const N: usize> Stack<V, N> {
+    /// Split its elements into two mutable slices, the bottom one
+    /// with the first `i` elements and the top one with the rest.
+    ///
+    /// # Panics
+    ///
+    /// If `i` is larger than the number of elements, it will panic.
+    #[inline]
+    pub fn split_at_mut(&mut self, i: usize) -> (&mut [V], &mut [V]) {
+        assert!(
+            i <= self.next,
+            "Index {i} is out of bounds of {}",
+            self.next
+        );
+        self.items[..self.next].split_at_mut(i)
+    }
+
+    /// Split its elements into the top `n` of them and the rest,
+    /// both as mutable slices, in the bottom-to-top order.
+    ///
+    /// # Panics
+    ///
+    /// If `n` is larger than the number of elements, it will panic.
+    #[inline]
+    pub fn split_top(&mut self, n: usize) -> (&mut [V], &mut [V]) {
+        assert!(n <= self.next, "No more items left in the stack");
+        let (rest, top) = self.split_at_mut(self.next - n);
+        (top, rest)
+    }
+
+    /// Iterate its elements in chunks of `size`, from the bottom
+    /// to the top; the last chunk may be shorter.
+    ///
+    /// # Panics
+    ///
+    /// If `size` is zero, it will panic.
+    #[inline]
+    pub fn chunks(&self, size: usize) -> Chunks<'_, V> {
+        self.items[..self.next].chunks(size)
+    }
+
+    /// Iterate its elements in chunks of `size`, from the top
+    /// to the bottom; the last chunk, at the bottom, may be shorter.
+    ///
+    /// # Panics
+    ///
+    /// If `size` is zero, it will panic.
+    #[inline]
+    pub fn rchunks(&self, size: usize) -> RChunks<'_, V> {
+        self.items[..self.next].rchunks(size)
+    }
+
+    /// Get the top `K` elements, in the bottom-to-top order.
+    ///
+    /// # Panics
+    ///
+    /// If there are less than `K` elements, it will panic.
+    #[inline]
+    #[must_use]
+    pub fn top_n<const K: usize>(&self) -> &[V; K] {
+        assert!(K <= self.next, "No more items left in the stack");
+        unsafe { &*self.items.as_ptr().add(self.next - K).cast::<[V; K]>() }
+    }
+}
+
+#[test]
+fn splits_at_index() {
+    let mut s: Stack<u8, 8> = Stack::from_array([1, 2, 3, 4]);
+    let (bottom, top) = s.split_at_mut(1);
+    assert_eq!(&[1], bottom);
+    assert_eq!(&[2, 3, 4], top);
+    bottom[0] = 10;
+    top[2] = 40;
+    assert_eq!(vec![10, 2, 3, 40], s.into_iter().collect::<Vec<_>>());
+}
+
+#[test]
+fn splits_at_edges() {
+    let mut s: Stack<u8, 8> = Stack::from_array([1, 2]);
+    assert_eq!((&mut [][..], &mut [1, 2][..]), s.split_at_mut(0));
+    assert_eq!((&mut [1, 2][..], &mut [][..]), s.split_at_mut(2));
+}
+
+#[test]
+#[should_panic(expected = "Index 3 is out of bounds of 2")]
+fn fails_to_split_beyond_length() {
+    let mut s: Stack<u8, 8> = Stack::from_array([1, 2]);
+    let _ = s.split_at_mut(3);
+}
+
+#[test]
+fn splits_top() {
+    let mut s: Stack<u8, 8> = Stack::from_array([1, 2, 3, 4]);
+    let (top, rest) = s.split_top(3);
+    assert_eq!(&[2, 3, 4], top);
+    assert_eq!(&[1], rest);
+    top.swap(0, 2);
+    assert_eq!(vec![1, 4, 3, 2], s.into_iter().collect::<Vec<_>>());
+}
+
+#[test]
+#[should_panic(expected = "No more items left in the stack")]
+fn fails_to_split_too_much_top() {
+    let mut s: Stack<u8, 8> = Stack::from_array([1, 2]);
+    let _ = s.split_top(3);
+}
+
+#[test]
+fn iterates_chunks() {
+    let s: Stack<u8, 8> = Stack::from_array([1, 2, 3, 4, 5]);
+    let chunks: Vec<&[u8]> = s.chunks(2).collect();
+    assert_eq!(vec![&[1, 2][..], &[3, 4], &[5]], chunks);
+    let rchunks: Vec<&[u8]> = s.rchunks(2).collect();
+    assert_eq!(vec![&[4, 5][..], &[2, 3], &[1]], rchunks);
+    assert_eq!(0, Stack::<u8, 8>::new().rchunks(2).count());
+}
+
+#[test]
+fn gets_top_n() {
+    let s: Stack<u8, 8> = Stack::from_array([1, 2, 3]);
+    assert_eq!(&[2, 3], s.top_n::<2>());
+    assert_eq!(&[1, 2, 3], s.top_n::<3>());
+    assert_eq!(&[] as &[u8; 0], s.top_n::<0>());
+}
+
+#[test]
+#[should_panic(expected = "No more items left in the stack")]
+fn fails_to_get_too_many_top() {
+    let s: Stack<u8, 8> = Stack::from_array([1, 2, 3]);
+    let _ = s.top_n::<4>();
+}
@@ -18,9 +18,13 @@
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 // SOFTWARE.
 
-use crate::Stack;
+use crate::{PopWhile, Stack};
+use std::ptr;
 
 impl<V: Copy, const N: usize> Stack<V, N> {
+    /// The capacity, which is the maximum number of elements in it.
+    pub const CAPACITY: usize = N;
+
     /// Make it from vector.
     #[inline]
     #[must_use]
@@ -35,8 +39,29 @@ impl<V: Copy, const N: usize> Stack<V, N> {
     /// Get the capacity.
     #[inline]
     #[must_use]
-    pub fn capacity(&mut self) -> usize {
-        N
+    pub const fn capacity(&self) -> usize {
+        Self::CAPACITY
+    }
+
+    /// Is there no more space left.
+    #[inline]
+    #[must_use]
+    pub const fn is_full(&self) -> bool {
+        self.next == N
+    }
+
+    /// Get the number of elements that may still be pushed.
+    #[inline]
+    #[must_use]
+    pub const fn remaining_capacity(&self) -> usize {
+        N - self.next
+    }
+
+    /// Check whether `n` more elements may be pushed.
+    #[inline]
+    #[must_use]
+    pub const fn try_reserve(&self, n: usize) -> bool {
+        n <= self.remaining_capacity()
     }
 
     /// Push new element into it.
@@ -45,7 +70,7 @@ impl<V: Copy, const N: usize> Stack<V, N> {
     ///
     /// It may lead to undefined behavior, if you go over the boundary.
     #[inline]
-    pub unsafe fn push_unchecked(&mut self, v: V) {
+    pub const unsafe fn push_unchecked(&mut self, v: V) {
         self.items.as_mut_ptr().add(self.next).write(v);
         self.next += 1;
     }
@@ -90,7 +115,7 @@ impl<V: Copy, const N: usize> Stack<V, N> {
     ///
     /// If there are no items in the array, the result is undefined.
     #[inline]
-    pub unsafe fn pop_unchecked(&mut self) -> V {
+    pub const unsafe fn pop_unchecked(&mut self) -> V {
         self.next -= 1;
         self.items.as_ptr().add(self.next).read()
     }
@@ -123,9 +148,254 @@ impl<V: Copy, const N: usize> Stack<V, N> {
         }
     }
 
+    /// Insert new element at the position, shifting all elements
+    /// above it up.
+    ///
+    /// # Safety
+    ///
+    /// It may lead to undefined behavior, if you go over the boundary,
+    /// or if the position is above the top.
+    #[inline]
+    pub const unsafe fn insert_unchecked(&mut self, i: usize, v: V) {
+        let p = self.items.as_mut_ptr().add(i);
+        ptr::copy(p, p.add(1), self.next - i);
+        p.write(v);
+        self.next += 1;
+    }
+
+    /// Insert new element at the position, shifting all elements
+    /// above it up.
+    ///
+    /// # Panics
+    ///
+    /// If there is no more space in the stack, or the position is
+    /// above the top, it will panic.
+    #[inline]
+    pub fn insert(&mut self, i: usize, v: V) {
+        assert!(self.next < N, "No more space left in the stack");
+        assert!(i <= self.next, "The position is above the top of the stack");
+        unsafe {
+            self.insert_unchecked(i, v);
+        }
+    }
+
+    /// Makes an attempt to insert new element at the position,
+    /// shifting all elements above it up.
+    ///
+    /// # Errors
+    ///
+    /// If there is not enough space in the stack, or the position is
+    /// above the top, `Err` is returned.
+    #[inline]
+    pub fn try_insert(&mut self, i: usize, v: V) -> Result<(), String> {
+        if self.next >= N {
+            Err(format!("There are no space left in the stack of {N}"))
+        } else if i > self.next {
+            Err(format!(
+                "The position {i} is above the top of the stack of {}",
+                self.next
+            ))
+        } else {
+            self.insert(i, v);
+            Ok(())
+        }
+    }
+
+    /// Remove the element at the position, shifting all elements
+    /// above it down.
+    ///
+    /// # Safety
+    ///
+    /// If the position is not below the top, the result is undefined.
+    #[inline]
+    pub const unsafe fn remove_unchecked(&mut self, i: usize) -> V {
+        let p = self.items.as_mut_ptr().add(i);
+        let v = p.read();
+        self.next -= 1;
+        ptr::copy(p.add(1), p, self.next - i);
+        v
+    }
+
+    /// Remove the element at the position, shifting all elements
+    /// above it down.
+    ///
+    /// # Panics
+    ///
+    /// If there is no element at the position, it will panic.
+    #[inline]
+    pub fn remove(&mut self, i: usize) -> V {
+        assert!(i < self.next, "There is no item at the position");
+        unsafe { self.remove_unchecked(i) }
+    }
+
+    /// Makes an attempt to remove the element at the position,
+    /// shifting all elements above it down.
+    ///
+    /// # Errors
+    ///
+    /// If there is no element at the position, `Err` is returned.
+    #[inline]
+    pub fn try_remove(&mut self, i: usize) -> Result<V, String> {
+        if i < self.next {
+            Ok(self.remove(i))
+        } else {
+            Err(format!(
+                "There is no item at {i} in the stack of {}",
+                self.next
+            ))
+        }
+    }
+
+    /// Remove the element at the position, replacing it with the top one.
+    ///
+    /// # Safety
+    ///
+    /// If the position is not below the top, the result is undefined.
+    #[inline]
+    pub const unsafe fn swap_remove_unchecked(&mut self, i: usize) -> V {
+        let p = self.items.as_mut_ptr();
+        let v = p.add(i).read();
+        self.next -= 1;
+        ptr::copy(p.add(self.next), p.add(i), 1);
+        v
+    }
+
+    /// Remove the element at the position, replacing it with the top one.
+    ///
+    /// # Panics
+    ///
+    /// If there is no element at the position, it will panic.
+    #[inline]
+    pub fn swap_remove(&mut self, i: usize) -> V {
+        assert!(i < self.next, "There is no item at the position");
+        unsafe { self.swap_remove_unchecked(i) }
+    }
+
+    /// Makes an attempt to remove the element at the position,
+    /// replacing it with the top one.
+    ///
+    /// # Errors
+    ///
+    /// If there is no element at the position, `Err` is returned.
+    #[inline]
+    pub fn try_swap_remove(&mut self, i: usize) -> Result<V, String> {
+        if i < self.next {
+            Ok(self.swap_remove(i))
+        } else {
+            Err(format!(
+                "There is no item at {i} in the stack of {}",
+                self.next
+            ))
+        }
+    }
+
+    /// Keep only the elements for which the predicate returns `true`,
+    /// preserving their order.
+    #[inline]
+    pub fn retain<F: FnMut(&V) -> bool>(&mut self, mut f: F) {
+        self.retain_mut(|v| f(v));
+    }
+
+    /// Keep only the elements for which the predicate returns `true`,
+    /// preserving their order, while letting it modify them.
+    #[inline]
+    pub fn retain_mut<F: FnMut(&mut V) -> bool>(&mut self, mut f: F) {
+        let p = self.items.as_mut_ptr();
+        let mut kept = 0;
+        for i in 0..self.next {
+            unsafe {
+                if f(&mut *p.add(i)) {
+                    ptr::copy(p.add(i), p.add(kept), 1);
+                    kept += 1;
+                }
+            }
+        }
+        self.next = kept;
+    }
+
+    /// Remove consecutive elements for which the function returns `true`,
+    /// given the element and the previous one kept.
+    #[inline]
+    pub fn dedup_by<F: FnMut(&V, &V) -> bool>(&mut self, mut same: F) {
+        if self.next < 2 {
+            return;
+        }
+        let p = self.items.as_mut_ptr();
+        let mut kept = 1;
+        for i in 1..self.next {
+            unsafe {
+                if !same(&*p.add(i), &*p.add(kept - 1)) {
+                    ptr::copy(p.add(i), p.add(kept), 1);
+                    kept += 1;
+                }
+            }
+        }
+        self.next = kept;
+    }
+
+    /// Remove consecutive elements that map to the same key.
+    #[inline]
+    pub fn dedup_by_key<K: PartialEq, F: FnMut(&V) -> K>(&mut self, mut key: F) {
+        self.dedup_by(|a, b| key(a) == key(b));
+    }
+
+    /// Get the top element, if any.
+    #[inline]
+    const fn top(&self) -> Option<&V> {
+        if self.next == 0 {
+            None
+        } else {
+            Some(unsafe { &*self.items.as_ptr().add(self.next - 1) })
+        }
+    }
+
+    /// Pop the top element, if it satisfies the predicate.
+    #[inline]
+    pub fn pop_if<F: FnOnce(&V) -> bool>(&mut self, pred: F) -> Option<V> {
+        if self.top().is_some_and(pred) {
+            Some(unsafe { self.pop_unchecked() })
+        } else {
+            None
+        }
+    }
+
+    /// Pop elements, while the top one satisfies the predicate.
+    #[inline]
+    pub const fn pop_while<F: FnMut(&V) -> bool>(&mut self, pred: F) -> PopWhile<'_, V, N, F> {
+        PopWhile { stack: self, pred }
+    }
+
+    /// Pop elements, until the top one satisfies the predicate, leaving
+    /// it on top.
+    #[inline]
+    pub fn pop_until<F: FnMut(&V) -> bool>(
+        &mut self,
+        mut pred: F,
+    ) -> PopWhile<'_, V, N, impl FnMut(&V) -> bool> {
+        self.pop_while(move |v| !pred(v))
+    }
+
+    /// Push new element, if the predicate, given the top one,
+    /// returns `true`.
+    ///
+    /// Returns `true` if the element was pushed.
+    ///
+    /// # Panics
+    ///
+    /// If the element must be pushed, but there is no more space
+    /// in the stack, it will panic.
+    #[inline]
+    pub fn push_if<F: FnOnce(Option<&V>) -> bool>(&mut self, cond: F, v: V) -> bool {
+        let ok = cond(self.top());
+        if ok {
+            self.push(v);
+        }
+        ok
+    }
+
     /// Clear.
     #[inline]
-    pub fn clear(&mut self) {
+    pub const fn clear(&mut self) {
         self.next = 0;
     }
 
@@ -142,6 +412,24 @@ impl<V: Copy, const N: usize> Stack<V, N> {
     pub const fn len(&self) -> usize {
         self.next
     }
+
+    /// Remove consecutive equal elements.
+    #[inline]
+    pub fn dedup(&mut self)
+    where
+        V: PartialEq,
+    {
+        self.dedup_by(|a, b| a == b);
+    }
+}
+
+impl<V: Copy, const N: usize, F: FnMut(&V) -> bool> Iterator for PopWhile<'_, V, N, F> {
+    type Item = V;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.stack.pop_if(&mut self.pred)
+    }
 }
 
 #[test]
@@ -199,10 +487,39 @@ fn pop_none() {
 
 #[test]
 fn read_capacity() {
-    let mut s: Stack<u64, 1> = Stack::new();
+    let s: Stack<u64, 1> = Stack::new();
     assert_eq!(1, s.capacity());
 }
 
+#[test]
+fn read_capacity_in_const_context() {
+    const CAP: usize = Stack::<u64, 7>::CAPACITY;
+    let buf = [0_u8; CAP];
+    assert_eq!(7, buf.len());
+}
+
+#[test]
+fn check_remaining_space() {
+    let mut s: Stack<u64, 2> = Stack::new();
+    assert!(!s.is_full());
+    assert_eq!(2, s.remaining_capacity());
+    assert!(s.try_reserve(2));
+    assert!(!s.try_reserve(3));
+    s.push(1);
+    s.push(2);
+    assert!(s.is_full());
+    assert_eq!(0, s.remaining_capacity());
+    assert!(s.try_reserve(0));
+    assert!(!s.try_reserve(1));
+}
+
+#[test]
+fn check_zero_capacity() {
+    let s: Stack<u64, 0> = Stack::new();
+    assert!(s.is_full());
+    assert!(s.is_empty());
+}
+
 #[test]
 fn safely_pop() {
     let mut s: Stack<u64, 1> = Stack::new();
@@ -218,7 +535,7 @@ fn with_str() {
 }
 
 #[test]
-#[should_panic]
+#[should_panic(expected = "No more space left in the stack")]
 fn panic_on_empty_stack_push() {
     let mut s: Stack<u64, 0> = Stack::new();
     assert_eq!(0, s.len());
@@ -226,7 +543,7 @@ fn panic_on_empty_stack_push() {
 }
 
 #[test]
-#[should_panic]
+#[should_panic(expected = "No more items left in the stack")]
 fn panic_on_empty_stack_pop() {
     let mut s: Stack<u64, 0> = Stack::new();
     assert_eq!(0, s.len());
@@ -239,3 +556,216 @@ fn push_and_pop() {
     s.push(42);
     assert_eq!(42, s.pop());
 }
+
+#[test]
+fn inserts_in_the_middle() {
+    let mut s: Stack<u64, 4> = Stack::from_vec(vec![1, 3]);
+    s.insert(1, 2);
+    s.insert(0, 0);
+    assert_eq!(vec![0, 1, 2, 3], s.into_iter().collect::<Vec<_>>());
+}
+
+#[test]
+fn fails_to_insert() {
+    let mut s: Stack<u64, 2> = Stack::new();
+    assert!(s.try_insert(1, 7).err().unwrap().contains("above"));
+    assert!(s.try_insert(0, 7).is_ok());
+    assert!(s.try_insert(1, 8).is_ok());
+    assert!(s.try_insert(0, 9).err().unwrap().contains("space"));
+}
+
+#[test]
+#[should_panic(expected = "The position is above the top of the stack")]
+fn panics_on_insert_above_top() {
+    let mut s: Stack<u64, 2> = Stack::new();
+    s.insert(1, 1);
+}
+
+#[test]
+fn removes_from_the_middle() {
+    let mut s: Stack<u64, 4> = Stack::from_vec(vec![1, 2, 3, 4]);
+    assert_eq!(2, s.remove(1));
+    assert_eq!(1, s.swap_remove(0));
+    assert_eq!(vec![4, 3], s.into_iter().collect::<Vec<_>>());
+}
+
+#[test]
+fn fails_to_remove() {
+    let mut s: Stack<u64, 4> = Stack::from_vec(vec![1]);
+    assert!(s.try_remove(1).err().unwrap().contains("no item"));
+    assert!(s.try_swap_remove(1).is_err());
+    assert_eq!(Ok(1), s.try_swap_remove(0));
+}
+
+#[test]
+#[should_panic(expected = "There is no item at the position")]
+fn panics_on_remove_above_top() {
+    let mut s: Stack<u64, 2> = Stack::new();
+    s.remove(0);
+}
+
+#[test]
+fn retains_and_modifies() {
+    let mut s: Stack<u64, 8> = Stack::from_vec(vec![1, 2, 3, 4, 5, 6]);
+    s.retain(|v| v % 2 == 0);
+    assert_eq!(vec![2, 4, 6], s.clone().into_iter().collect::<Vec<_>>());
+    s.retain_mut(|v| {
+        *v *= 10;
+        *v > 20
+    });
+    assert_eq!(vec![40, 60], s.into_iter().collect::<Vec<_>>());
+}
+
+#[test]
+fn dedups_consecutive() {
+    let mut s: Stack<u64, 8> = Stack::from_vec(vec![1, 1, 2, 2, 2, 1, 3, 3]);
+    s.dedup();
+    assert_eq!(vec![1, 2, 1, 3], s.into_iter().collect::<Vec<_>>());
+    let mut k: Stack<i64, 8> = Stack::from_vec(vec![1, -1, 2, -2, -3]);
+    k.dedup_by_key(|v| v.abs());
+    assert_eq!(vec![1, 2, -3], k.into_iter().collect::<Vec<_>>());
+}
+
+#[cfg(test)]
+use proptest::prelude::*;
+
+#[cfg(test)]
+#[derive(Clone, Copy, Debug)]
+enum Edit {
+    Insert(usize, u8),
+    Remove(usize),
+    SwapRemove(usize),
+    Retain(u8),
+    Dedup,
+    Push(u8),
+}
+
+#[cfg(test)]
+fn edit() -> impl Strategy<Value = Edit> {
+    prop_oneof![
+        (any::<usize>(), 0..4u8).prop_map(|(i, v)| Edit::Insert(i, v)),
+        any::<usize>().prop_map(Edit::Remove),
+        any::<usize>().prop_map(Edit::SwapRemove),
+        (0..4u8).prop_map(Edit::Retain),
+        Just(Edit::Dedup),
+        (0..4u8).prop_map(Edit::Push),
+    ]
+}
+
+#[cfg(test)]
+proptest! {
+    #[test]
+    fn edits_like_vec(edits in proptest::collection::vec(edit(), 0..256)) {
+        let mut s: Stack<u8, 16> = Stack::new();
+        let mut model: Vec<u8> = vec![];
+        for e in edits {
+            match e {
+                Edit::Insert(i, v) => {
+                    let i = i % (model.len() + 1);
+                    let inserted = s.try_insert(i, v).is_ok();
+                    prop_assert_eq!(model.len() < 16, inserted);
+                    if inserted {
+                        model.insert(i, v);
+                    }
+                }
+                Edit::Remove(i) => {
+                    let i = i % (model.len() + 1);
+                    prop_assert_eq!(
+                        (i < model.len()).then(|| model.remove(i)),
+                        s.try_remove(i).ok()
+                    );
+                }
+                Edit::SwapRemove(i) => {
+                    let i = i % (model.len() + 1);
+                    prop_assert_eq!(
+                        (i < model.len()).then(|| model.swap_remove(i)),
+                        s.try_swap_remove(i).ok()
+                    );
+                }
+                Edit::Retain(v) => {
+                    model.retain(|x| *x != v);
+                    s.retain(|x| *x != v);
+                }
+                Edit::Dedup => {
+                    model.dedup();
+                    s.dedup();
+                }
+                Edit::Push(v) => {
+                    let pushed = s.try_push(v).is_ok();
+                    prop_assert_eq!(model.len() < 16, pushed);
+                    if pushed {
+                        model.push(v);
+                    }
+                }
+            }
+            prop_assert!(model.iter().eq(s.iter()));
+        }
+    }
+}
+
+#[test]
+fn pops_if_top_matches() {
+    let mut s: Stack<u64, 4> = Stack::from_vec(vec![1, 2]);
+    assert_eq!(None, s.pop_if(|v| *v == 1));
+    assert_eq!(Some(2), s.pop_if(|v| *v == 2));
+    assert_eq!(Some(1), s.pop_if(|_| true));
+    assert_eq!(None, s.pop_if(|_| true));
+}
+
+#[test]
+fn pops_while_and_until() {
+    let mut s: Stack<u64, 8> = Stack::from_vec(vec![7, 1, 2, 3, 9, 8]);
+    assert_eq!(vec![8, 9], s.pop_while(|v| *v > 3).collect::<Vec<_>>());
+    assert_eq!(vec![3, 2], s.pop_until(|v| *v == 1).collect::<Vec<_>>());
+    assert_eq!(2, s.len());
+    assert_eq!(vec![1, 7], s.pop_while(|_| true).collect::<Vec<_>>());
+    assert!(s.pop_until(|_| false).next().is_none());
+}
+
+#[test]
+fn pops_lazily() {
+    let mut s: Stack<u64, 8> = Stack::from_vec(vec![1, 2, 3]);
+    assert_eq!(Some(3), s.pop_while(|_| true).next());
+    assert_eq!(2, s.len());
+}
+
+#[test]
+fn pushes_if_top_allows() {
+    let mut s: Stack<u64, 4> = Stack::new();
+    assert!(s.push_if(|top| top.is_none(), 1));
+    assert!(!s.push_if(|top| top.is_none(), 2));
+    assert!(s.push_if(|top| top != Some(&2), 2));
+    assert!(!s.push_if(|top| top != Some(&2), 2));
+    assert_eq!(2, s.len());
+}
+
+#[cfg(test)]
+const fn precedence(op: u8) -> u8 {
+    match op {
+        b'*' | b'/' => 2,
+        b'+' | b'-' => 1,
+        _ => 0,
+    }
+}
+
+#[test]
+fn converts_infix_to_postfix() {
+    let mut out = vec![];
+    let mut ops: Stack<u8, 16> = Stack::new();
+    for c in b"3+4*2/(1-5)-6".iter().copied() {
+        match c {
+            b'0'..=b'9' => out.push(c),
+            b'(' => ops.push(c),
+            b')' => {
+                out.extend(ops.pop_until(|op| *op == b'('));
+                ops.pop();
+            }
+            _ => {
+                out.extend(ops.pop_while(|op| precedence(*op) >= precedence(c)));
+                ops.push(c);
+            }
+        }
+    }
+    out.extend(ops.pop_while(|_| true));
+    assert_eq!(b"342*15-/+6-".as_slice(), out.as_slice());
+}
@@ -0,0 +1,185 @@
+// Copyright (c) 2023 Yegor Bugayenko
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{Direction, IntoStream, Overflow, Stack, StackExt};
+use futures::{Stream, StreamExt};
+use std::fmt::{self, Debug, Display, Formatter};
+use std::pin::{pin, Pin};
+use std::task::{Context, Poll};
+
+impl<S: Stream + Sized> StackExt for S
+where
+    S::Item: Copy,
+{
+    async fn collect_stream<const N: usize>(
+        self,
+    ) -> Result<Stack<S::Item, N>, Overflow<S::Item, N>> {
+        let mut stream = pin!(self);
+        let mut stack = Stack::new();
+        while let Some(item) = stream.next().await {
+            if stack.is_full() {
+                return Err(Overflow { stack, item });
+            }
+            unsafe { stack.push_unchecked(item) };
+        }
+        Ok(stack)
+    }
+}
+
+impl<V: Copy, const N: usize> Stack<V, N> {
+    /// Make a stream of its elements, in the given order.
+    #[inline]
+    #[must_use]
+    pub const fn into_stream(self, direction: Direction) -> IntoStream<V, N> {
+        IntoStream {
+            stack: self,
+            pos: 0,
+            direction,
+        }
+    }
+}
+
+/// The elements are never pinned, since they are only copied out.
+impl<V: Copy, const N: usize> Unpin for IntoStream<V, N> {}
+
+impl<V: Copy, const N: usize> Stream for IntoStream<V, N> {
+    type Item = V;
+
+    fn poll_next(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Option<V>> {
+        let s = self.get_mut();
+        if s.pos == s.stack.next {
+            return Poll::Ready(None);
+        }
+        Poll::Ready(Some(match s.direction {
+            Direction::Lifo => unsafe { s.stack.pop_unchecked() },
+            Direction::Fifo => {
+                s.pos += 1;
+                s.stack.items[s.pos - 1]
+            }
+        }))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let left = self.stack.next - self.pos;
+        (left, Some(left))
+    }
+}
+
+impl<V: Copy, const N: usize> Display for Overflow<V, N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "There are no space left in the stack of {N}")
+    }
+}
+
+impl<V: Copy + Debug, const N: usize> std::error::Error for Overflow<V, N> {}
+
+#[cfg(test)]
+use futures::executor::block_on;
+#[cfg(test)]
+use futures::stream;
+
+#[test]
+fn collects_stream() {
+    let s: Stack<u8, 4> = block_on(stream::iter([1, 2, 3]).collect_stream()).unwrap();
+    assert_eq!(vec![1, 2, 3], s.into_iter().collect::<Vec<_>>());
+}
+
+#[test]
+fn collects_empty_stream() {
+    let s: Stack<u8, 4> = block_on(stream::empty().collect_stream()).unwrap();
+    assert!(s.is_empty());
+}
+
+#[test]
+fn collects_stream_into_full_stack() {
+    let s: Stack<u8, 3> = block_on(stream::iter([1, 2, 3]).collect_stream()).unwrap();
+    assert!(s.is_full());
+}
+
+#[test]
+fn stops_on_overflow() {
+    let mut source = stream::iter(1..=6);
+    let e: Overflow<u8, 3> = block_on((&mut source).collect_stream()).unwrap_err();
+    assert_eq!("There are no space left in the stack of 3", e.to_string());
+    assert_eq!(4, e.item);
+    assert_eq!(vec![1, 2, 3], e.stack.into_iter().collect::<Vec<_>>());
+    assert_eq!(vec![5, 6], block_on(source.collect::<Vec<_>>()));
+}
+
+#[test]
+fn collects_pending_stream() {
+    let (tx, rx) = futures::channel::mpsc::unbounded();
+    let producer = async move {
+        for i in 0..3 {
+            let mut yielded = false;
+            futures::future::poll_fn(|cx| {
+                if yielded {
+                    return Poll::Ready(());
+                }
+                yielded = true;
+                cx.waker().wake_by_ref();
+                Poll::Pending
+            })
+            .await;
+            tx.unbounded_send(i).unwrap();
+        }
+    };
+    let ((), s) = block_on(futures::future::join(producer, rx.collect_stream::<4>()));
+    assert_eq!(vec![0, 1, 2], s.unwrap().into_iter().collect::<Vec<_>>());
+}
+
+#[test]
+fn streams_in_lifo_order() {
+    let s: Stack<u8, 4> = Stack::from_array([1, 2, 3]);
+    let items: Vec<u8> = block_on(s.into_stream(Direction::Lifo).collect());
+    assert_eq!(vec![3, 2, 1], items);
+}
+
+#[test]
+fn streams_in_fifo_order() {
+    let s: Stack<u8, 4> = Stack::from_array([1, 2, 3]);
+    let stream = s.into_stream(Direction::Fifo);
+    assert_eq!((3, Some(3)), stream.size_hint());
+    let items: Vec<u8> = block_on(stream.collect());
+    assert_eq!(vec![1, 2, 3], items);
+}
+
+#[test]
+fn streams_empty_stack() {
+    let s: Stack<u8, 4> = Stack::new();
+    assert_eq!(0, block_on(s.clone().into_stream(Direction::Lifo).count()));
+    assert_eq!(0, block_on(s.into_stream(Direction::Fifo).count()));
+}
+
+#[test]
+fn round_trips_through_stream() {
+    let s: Stack<u8, 4> = Stack::from_array([1, 2, 3, 4]);
+    let t: Stack<u8, 4> = block_on(s.into_stream(Direction::Fifo).collect_stream()).unwrap();
+    assert_eq!(vec![1, 2, 3, 4], t.into_iter().collect::<Vec<_>>());
+}
+
+#[test]
+fn streams_elements_that_are_not_unpin() {
+    #[derive(Clone, Copy)]
+    struct Pinned(u8, std::marker::PhantomPinned);
+    let s: Stack<Pinned, 4> = Stack::from_array([Pinned(1, std::marker::PhantomPinned)]);
+    let items: Vec<Pinned> = block_on(s.into_stream(Direction::Lifo).collect());
+    assert_eq!(1, items[0].0);
+}
@@ -0,0 +1,258 @@
+// Copyright (c) 2023 Yegor Bugayenko
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{Stack, StackString};
+use std::fmt;
+use std::ops::Deref;
+use std::ptr;
+use std::str::FromStr;
+
+impl<const N: usize> Default for StackString<N> {
+    /// Make a default empty [`StackString`].
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> Deref for StackString<N> {
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> FromStr for StackString<N> {
+    type Err = String;
+
+    /// Make it from a string, which must fit.
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut p = Self::new();
+        p.try_push_str(s)?;
+        Ok(p)
+    }
+}
+
+impl<const N: usize> fmt::Write for StackString<N> {
+    /// Append the string, failing if it doesn't fit.
+    #[inline]
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.try_push_str(s).map_err(|_| fmt::Error)
+    }
+}
+
+impl<const N: usize> StackString<N> {
+    /// Make it.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            stack: Stack::new(),
+        }
+    }
+
+    /// Make it from bytes, which must be a valid UTF-8 and must fit.
+    ///
+    /// # Errors
+    ///
+    /// If the bytes are not a valid UTF-8 or there are too many of them,
+    /// `Err` is returned.
+    #[inline]
+    pub fn from_utf8(bytes: &[u8]) -> Result<Self, String> {
+        std::str::from_utf8(bytes)
+            .map_err(|e| format!("The bytes are not a valid UTF-8: {e}"))?
+            .parse()
+    }
+
+    /// Get it as a string slice.
+    #[inline]
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        unsafe { std::str::from_utf8_unchecked(self.as_bytes()) }
+    }
+
+    /// Get it as a slice of bytes.
+    #[inline]
+    #[must_use]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.stack.items[..self.stack.next]
+    }
+
+    /// Append the string.
+    ///
+    /// # Safety
+    ///
+    /// It may lead to undefined behavior, if you go over the boundary.
+    #[inline]
+    pub const unsafe fn push_str_unchecked(&mut self, s: &str) {
+        ptr::copy_nonoverlapping(
+            s.as_ptr(),
+            self.stack.items.as_mut_ptr().add(self.stack.next),
+            s.len(),
+        );
+        self.stack.next += s.len();
+    }
+
+    /// Append the string.
+    ///
+    /// # Panics
+    ///
+    /// If there is not enough space for the string, it will panic.
+    #[inline]
+    pub fn push_str(&mut self, s: &str) {
+        assert!(
+            s.len() <= N - self.stack.next,
+            "No more space left in the string"
+        );
+        unsafe { self.push_str_unchecked(s) }
+    }
+
+    /// Makes an attempt to append the string.
+    ///
+    /// # Errors
+    ///
+    /// If there is not enough space for the string, `Err` is returned
+    /// and nothing is appended.
+    #[inline]
+    pub fn try_push_str(&mut self, s: &str) -> Result<(), String> {
+        if s.len() <= N - self.stack.next {
+            self.push_str(s);
+            Ok(())
+        } else {
+            Err(format!(
+                "There are no space left for {} bytes in the string of {N}",
+                s.len()
+            ))
+        }
+    }
+
+    /// Append the char.
+    ///
+    /// # Safety
+    ///
+    /// It may lead to undefined behavior, if you go over the boundary.
+    #[inline]
+    pub const unsafe fn push_unchecked(&mut self, c: char) {
+        self.push_str_unchecked(c.encode_utf8(&mut [0; 4]));
+    }
+
+    /// Append the char.
+    ///
+    /// # Panics
+    ///
+    /// If there is not enough space for the char, it will panic.
+    #[inline]
+    pub fn push(&mut self, c: char) {
+        self.push_str(c.encode_utf8(&mut [0; 4]));
+    }
+
+    /// Makes an attempt to append the char.
+    ///
+    /// # Errors
+    ///
+    /// If there is not enough space for the char, `Err` is returned.
+    #[inline]
+    pub fn try_push(&mut self, c: char) -> Result<(), String> {
+        self.try_push_str(c.encode_utf8(&mut [0; 4]))
+    }
+
+    /// Remove the last char and return it, if any.
+    #[inline]
+    pub fn pop(&mut self) -> Option<char> {
+        let c = self.as_str().chars().next_back()?;
+        self.stack.next -= c.len_utf8();
+        Some(c)
+    }
+
+    /// Get the capacity, in bytes.
+    #[inline]
+    #[must_use]
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Clear.
+    #[inline]
+    pub const fn clear(&mut self) {
+        self.stack.clear();
+    }
+}
+
+#[test]
+fn pushes_and_pops_chars() {
+    let mut s: StackString<8> = StackString::new();
+    s.push('a');
+    s.push('ж');
+    s.push('€');
+    assert_eq!("aж€", s.as_str());
+    assert_eq!(6, s.len());
+    assert_eq!(Some('€'), s.pop());
+    assert_eq!(Some('ж'), s.pop());
+    assert_eq!(Some('a'), s.pop());
+    assert_eq!(None, s.pop());
+}
+
+#[test]
+fn rejects_overflow_without_partial_writes() {
+    let mut s: StackString<4> = StackString::new();
+    s.push_str("ab");
+    assert!(s.try_push_str("cde").err().unwrap().contains("space"));
+    assert!(s.try_push('€').is_err());
+    assert_eq!("ab", s.as_str());
+    assert!(s.try_push_str("cd").is_ok());
+    assert_eq!("abcd", &*s);
+}
+
+#[test]
+#[should_panic(expected = "No more space left in the string")]
+fn panics_on_overflow() {
+    let mut s: StackString<1> = StackString::new();
+    s.push('ж');
+}
+
+#[test]
+fn parses_from_str() {
+    let s: StackString<8> = "hello".parse().unwrap();
+    assert!(s.starts_with("he"));
+    assert!("too long for it".parse::<StackString<8>>().is_err());
+}
+
+#[test]
+fn validates_utf8() {
+    assert_eq!("ok", StackString::<4>::from_utf8(b"ok").unwrap().as_str());
+    assert!(StackString::<4>::from_utf8(&[0xC3, 0x28])
+        .err()
+        .unwrap()
+        .contains("UTF-8"));
+}
+
+#[test]
+fn writes_formatted() {
+    use std::fmt::Write;
+    let mut s: StackString<16> = StackString::new();
+    write!(s, "E{:04}", 42).unwrap();
+    assert_eq!("E0042", s.as_str());
+    assert!(write!(s, "{}", "x".repeat(20)).is_err());
+    s.clear();
+    assert!(s.is_empty());
+}
@@ -0,0 +1,156 @@
+// Copyright (c) 2023 Yegor Bugayenko
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::Stack;
+
+impl<V: Copy, const N: usize> Stack<V, N> {
+    /// Make a new stack of the same capacity, applying the function
+    /// to each element, from the bottom to the top.
+    #[inline]
+    #[must_use]
+    pub fn map<U: Copy, F: FnMut(V) -> U>(self, mut f: F) -> Stack<U, N> {
+        let mut p = Stack::new();
+        for v in self {
+            unsafe { p.push_unchecked(f(v)) };
+        }
+        p
+    }
+
+    /// Make a new stack of the same capacity, applying the fallible
+    /// function to each element, from the bottom to the top.
+    ///
+    /// # Errors
+    ///
+    /// The first error returned by the function is returned, and the
+    /// rest of the elements are not visited.
+    #[inline]
+    pub fn try_map<U: Copy, E, F: FnMut(V) -> Result<U, E>>(
+        self,
+        mut f: F,
+    ) -> Result<Stack<U, N>, E> {
+        let mut p = Stack::new();
+        for v in self {
+            unsafe { p.push_unchecked(f(v)?) };
+        }
+        Ok(p)
+    }
+
+    /// Pair up the elements of the two stacks, starting from the bottom.
+    ///
+    /// If one of them is longer, its extra top elements are ignored.
+    #[inline]
+    #[must_use]
+    pub fn zip<W: Copy>(self, other: Stack<W, N>) -> Stack<(V, W), N> {
+        let mut p = Stack::new();
+        for pair in self.into_iter().zip(other) {
+            unsafe { p.push_unchecked(pair) };
+        }
+        p
+    }
+
+    /// Make a stack of references to its elements.
+    #[inline]
+    #[must_use]
+    pub fn each_ref(&self) -> Stack<&V, N> {
+        let mut p = Stack::new();
+        for v in &self.items[..self.next] {
+            unsafe { p.push_unchecked(v) };
+        }
+        p
+    }
+
+    /// Get mutable access to its elements, from the bottom to the top.
+    ///
+    /// Mutable references are not `Copy`, and can't be elements of
+    /// a [`Stack`], that's why a slice is returned.
+    #[inline]
+    #[must_use]
+    pub fn each_mut(&mut self) -> &mut [V] {
+        &mut self.items[..self.next]
+    }
+}
+
+#[test]
+fn maps_stack() {
+    let mut s: Stack<u32, 64> = Stack::new();
+    s.push(1);
+    s.push(2);
+    let m: Stack<f64, 64> = s.map(|v| f64::from(v) / 2.0);
+    assert_eq!(vec![0.5, 1.0], m.into_iter().collect::<Vec<_>>());
+}
+
+#[test]
+fn maps_empty_stack() {
+    let s: Stack<u8, 4> = Stack::new();
+    assert!(s.map(|v| v + 1).is_empty());
+}
+
+#[test]
+fn maps_full_stack() {
+    let s: Stack<u8, 3> = Stack::from_array([1, 2, 3]);
+    let m = s.map(u16::from);
+    assert!(m.is_full());
+    assert_eq!(vec![1, 2, 3], m.into_iter().collect::<Vec<_>>());
+}
+
+#[test]
+fn tries_to_map_stack() {
+    let s: Stack<&str, 4> = Stack::from_array(["1", "2"]);
+    let m = s.try_map(str::parse::<u8>).unwrap();
+    assert_eq!(vec![1, 2], m.into_iter().collect::<Vec<_>>());
+}
+
+#[test]
+fn stops_mapping_on_first_error() {
+    let s: Stack<&str, 4> = Stack::from_array(["1", "x", "y"]);
+    let mut seen = 0;
+    let r = s.try_map(|v| {
+        seen += 1;
+        v.parse::<u8>().map_err(|_| v)
+    });
+    assert_eq!(Err("x"), r.map(|_| ()));
+    assert_eq!(2, seen);
+}
+
+#[test]
+fn zips_stacks() {
+    let a: Stack<u8, 4> = Stack::from_array([1, 2, 3]);
+    let b: Stack<char, 4> = Stack::from_array(['a', 'b']);
+    let z = a.zip(b);
+    assert_eq!(vec![(1, 'a'), (2, 'b')], z.into_iter().collect::<Vec<_>>());
+}
+
+#[test]
+fn makes_stack_of_refs() {
+    let s: Stack<u8, 4> = Stack::from_array([1, 2]);
+    let r = s.each_ref();
+    assert_eq!(2, r.len());
+    assert_eq!(vec![&1, &2], r.into_iter().collect::<Vec<_>>());
+}
+
+#[test]
+fn modifies_each_element() {
+    let mut s: Stack<u8, 4> = Stack::from_array([1, 2]);
+    for v in s.each_mut() {
+        *v *= 10;
+    }
+    assert_eq!(vec![10, 20], s.into_iter().collect::<Vec<_>>());
+    assert!(Stack::<u8, 4>::new().each_mut().is_empty());
+}